@@ -0,0 +1,145 @@
+//! Helpers for working with more than one channel at a time.
+//!
+//! Like [`OrClosed`](crate::OrClosed), the receiving helpers panic once
+//! there is nothing left that could ever be received. The forwarding
+//! helpers instead stop quietly when a channel closes, as a closed end
+//! is how the rest of a pipeline shuts them down.
+
+/// A value received from one of two channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+/// Receives from whichever of two crossbeam channels has a value first.
+///
+/// A channel that is closed is ignored while the other one is still
+/// open, so this only panics once both are closed.
+///
+/// ```
+/// use crossbeam_channel::unbounded;
+/// use semtraits::channel::{Either, recv_either};
+///
+/// let (numbers, numbers_rx) = unbounded();
+/// let (words, words_rx) = unbounded::<&str>();
+/// numbers.send(1).unwrap();
+/// assert_eq!(recv_either(&numbers_rx, &words_rx), Either::Left(1));
+///
+/// drop(numbers);
+/// words.send("hi").unwrap();
+/// assert_eq!(recv_either(&numbers_rx, &words_rx), Either::Right("hi"));
+/// ```
+///
+/// ```should_panic
+/// use crossbeam_channel::unbounded;
+/// use semtraits::channel::recv_either;
+///
+/// let (_, a) = unbounded::<i32>();
+/// let (_, b) = unbounded::<i32>();
+/// recv_either(&a, &b); // panics, receiving with no senders
+/// ```
+#[cfg(feature = "crossbeam-channel")]
+#[track_caller]
+pub fn recv_either<A, B>(
+    a: &crossbeam_channel::Receiver<A>,
+    b: &crossbeam_channel::Receiver<B>,
+) -> Either<A, B> {
+    use crate::impls::{RECV_PANIC_MESSAGE, fail};
+
+    let received = crossbeam_channel::select! {
+        // once one side closes, only the other can still receive.
+        recv(a) -> value => match value {
+            Ok(value) => Ok(Either::Left(value)),
+            Err(_) => b.recv().map(Either::Right),
+        },
+        recv(b) -> value => match value {
+            Ok(value) => Ok(Either::Right(value)),
+            Err(_) => a.recv().map(Either::Left),
+        },
+    };
+    // matched rather than unwrapped, so the panic points at the caller.
+    match received {
+        Ok(either) => either,
+        Err(_) => fail(format_args!("{RECV_PANIC_MESSAGE}")),
+    }
+}
+
+/// Forwards every value from `rx` to `tx` until either end is closed.
+///
+/// A closed `tx` is only noticed when the next value fails to send,
+/// and that value is dropped.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::channel::pipe_to;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (input, input_rx) = mpsc::channel(4);
+/// let (output, mut output_rx) = mpsc::channel(4);
+/// tokio::spawn(pipe_to(input_rx, output));
+///
+/// input.send(1).await.unwrap();
+/// drop(input);
+/// assert_eq!(output_rx.recv().await, Some(1));
+/// assert_eq!(output_rx.recv().await, None);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn pipe_to<T>(mut rx: tokio::sync::mpsc::Receiver<T>, tx: tokio::sync::mpsc::Sender<T>) {
+    while let Some(value) = rx.recv().await {
+        if tx.send(value).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Merges several receivers into one, which closes once all of them
+/// have closed.
+///
+/// Each input is forwarded with [`pipe_to`] on its own task, so values
+/// from a single input stay in order but values from different inputs
+/// can interleave. The output buffers as many values as the largest
+/// input does.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::channel::fan_in;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (a, a_rx) = mpsc::channel(1);
+/// let (b, b_rx) = mpsc::channel(1);
+/// let mut merged = fan_in(vec![a_rx, b_rx]);
+/// a.send(1).await.unwrap();
+/// b.send(2).await.unwrap();
+/// drop((a, b));
+///
+/// let mut values = vec![merged.recv().await.unwrap(), merged.recv().await.unwrap()];
+/// values.sort();
+/// assert_eq!(values, [1, 2]);
+/// assert_eq!(merged.recv().await, None);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime.
+#[cfg(feature = "tokio")]
+pub fn fan_in<T: Send + 'static>(
+    receivers: std::vec::Vec<tokio::sync::mpsc::Receiver<T>>,
+) -> tokio::sync::mpsc::Receiver<T> {
+    use crate::Share;
+
+    let capacity = receivers
+        .iter()
+        .map(tokio::sync::mpsc::Receiver::max_capacity)
+        .max()
+        .unwrap_or(1);
+    let (tx, rx) = tokio::sync::mpsc::channel(capacity);
+    for input in receivers {
+        tokio::spawn(pipe_to(input, tx.share()));
+    }
+    rx
+}
@@ -0,0 +1,52 @@
+//! async-channel errors are the same for the async and blocking methods,
+//! so `or_closed` and `send_or` cover both.
+//!
+//! ```
+//! use semtraits::{OrClosed, Share};
+//!
+//! let (tx, rx) = async_channel::bounded(1);
+//! let tx1 = tx.share();
+//! drop(tx);
+//! tx1.send_blocking(1).or_closed();
+//! assert_eq!(rx.recv_blocking().or_closed(), 1);
+//! ```
+
+use async_channel::{Receiver, RecvError, SendError, Sender};
+
+use super::{RECV_PANIC_MESSAGE, SEND_PANIC_MESSAGE};
+use crate::{ChannelClosed, RecvOr, SendOr, Share};
+
+impl<T> Share for Sender<T> {}
+impl<T> Share for Receiver<T> {}
+
+impl<T> ChannelClosed for SendError<T> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl ChannelClosed for RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> SendOr for Result<(), SendError<T>> {
+    type Unsent = T;
+
+    fn send_or(self, f: impl FnOnce(T)) -> Result<(), ()> {
+        self.map_err(|SendError(unsent)| f(unsent))
+    }
+}
+
+/// An async-channel is closed once every sender or every receiver is
+/// dropped, or when [`close`](Sender::close) is called on either end.
+/// Values that were already sent are still received after that, so the
+/// fallback only runs once the channel is both closed and empty.
+impl<T> RecvOr for Result<T, RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|RecvError| f())
+    }
+}
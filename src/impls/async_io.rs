@@ -0,0 +1,19 @@
+use std::io;
+
+use async_io::Async;
+
+use crate::CloneFd;
+
+#[cfg(unix)]
+impl<T: CloneFd + std::os::fd::AsFd> CloneFd for Async<T> {
+    fn clone_fd(&self) -> io::Result<Self> {
+        Async::new(self.get_ref().clone_fd()?)
+    }
+}
+
+#[cfg(windows)]
+impl<T: CloneFd + std::os::windows::io::AsSocket> CloneFd for Async<T> {
+    fn clone_fd(&self) -> io::Result<Self> {
+        Async::new(self.get_ref().clone_fd()?)
+    }
+}
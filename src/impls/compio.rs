@@ -0,0 +1,7 @@
+use compio::runtime::{CancelToken, Runtime};
+
+use crate::Share;
+
+// both are `Rc`-backed, so they are shared within the runtime's thread.
+impl Share for Runtime {}
+impl Share for CancelToken {}
@@ -0,0 +1,92 @@
+//! Both ends of a crossbeam channel can be cloned, and every clone
+//! shares the same queue.
+//!
+//! ```
+//! use crossbeam_channel::unbounded;
+//! use semtraits::{OrClosed, Share};
+//!
+//! let (tx, rx) = unbounded();
+//! let rx1 = rx.share();
+//! tx.send(1).or_closed();
+//! tx.send(2).or_closed();
+//! // each value goes to only one of the receivers.
+//! assert_eq!(rx.recv().or_closed(), 1);
+//! assert_eq!(rx1.recv().or_closed(), 2);
+//! ```
+
+use crossbeam_channel::{Receiver, RecvError, RecvTimeoutError, SendError, Sender, TrySendError};
+
+use super::{RECV_PANIC_MESSAGE, SEND_PANIC_MESSAGE, TIMEOUT_PANIC_MESSAGE, fail};
+use crate::{
+    ChannelClosed, IntoUnsent, OrTimeout, RecvOr, RecvTimeoutOr, SendOr, Share, TrySendOr,
+};
+
+impl<T> Share for Sender<T> {}
+impl<T> Share for Receiver<T> {}
+
+impl<T> ChannelClosed for SendError<T> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl ChannelClosed for RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> SendOr for Result<(), SendError<T>> {
+    type Unsent = T;
+
+    fn send_or(self, f: impl FnOnce(T)) -> Result<(), ()> {
+        self.map_err(|SendError(unsent)| f(unsent))
+    }
+}
+
+impl<T> RecvOr for Result<T, RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|RecvError| f())
+    }
+}
+
+impl<T> OrTimeout for Result<T, RecvTimeoutError> {
+    type Value = T;
+
+    fn or_timeout(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(RecvTimeoutError::Timeout) => panic!("{TIMEOUT_PANIC_MESSAGE}"),
+            Err(RecvTimeoutError::Disconnected) => fail(format_args!("{RECV_PANIC_MESSAGE}")),
+        }
+    }
+}
+
+impl<T> RecvTimeoutOr for Result<T, RecvTimeoutError> {
+    type Value = T;
+
+    fn recv_timeout_or(self, f: impl FnOnce(bool) -> T) -> T {
+        self.unwrap_or_else(|e| f(e.is_timeout()))
+    }
+}
+
+impl<T> IntoUnsent for Result<(), TrySendError<T>> {
+    type Unsent = T;
+
+    fn into_unsent(self) -> Result<(), T> {
+        self.map_err(|(TrySendError::Full(unsent) | TrySendError::Disconnected(unsent))| unsent)
+    }
+}
+
+impl<T> TrySendOr for Result<(), TrySendError<T>> {
+    type Unsent = T;
+
+    fn try_send_or(self, on_full: impl FnOnce(T), on_closed: impl FnOnce(T)) -> Result<(), ()> {
+        self.map_err(|e| match e {
+            TrySendError::Full(unsent) => on_full(unsent),
+            TrySendError::Disconnected(unsent) => on_closed(unsent),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use core::hash::{BuildHasher, Hash};
+
+use evmap::{MapReadRef, ReadHandle, ReadHandleFactory};
+
+use crate::{Share, Snapshot, shared::MapSnapshot};
+
+impl<K: Eq + Hash, V, M, S: BuildHasher> Share for ReadHandle<K, V, M, S> {}
+impl<K: Eq + Hash, V, M, S: BuildHasher> Share for ReadHandleFactory<K, V, M, S> {}
+
+/// An evmap is also unreadable until the writer first calls
+/// [`refresh`](evmap::WriteHandle::refresh), so the snapshot is `None`
+/// before then as well.
+impl<K, V, M, S> Snapshot for ReadHandle<K, V, M, S>
+where
+    K: Eq + Hash,
+    V: Eq + Hash,
+    M: Clone,
+    S: BuildHasher,
+{
+    type Target<'a>
+        = MapReadRef<'a, K, V, M, S>
+    where
+        Self: 'a;
+    type Guard<'a>
+        = MapSnapshot<'a, K, V, M, S>
+    where
+        Self: 'a;
+
+    fn snapshot(&self) -> Option<MapSnapshot<'_, K, V, M, S>> {
+        self.read().map(MapSnapshot)
+    }
+}
@@ -0,0 +1,43 @@
+//! flume's async methods resolve to the same errors as the blocking
+//! ones, so `or_closed` works the same on both.
+//!
+//! ```
+//! use semtraits::{OrClosed, Share};
+//!
+//! # futures::executor::block_on(async {
+//! let (tx, rx) = flume::unbounded();
+//! let tx1 = tx.share();
+//! tx.send(1).or_closed();
+//! tx1.send_async(2).await.or_closed();
+//! assert_eq!(rx.recv_async().await.or_closed(), 1);
+//! assert_eq!(rx.recv().or_closed(), 2);
+//! # });
+//! ```
+
+use flume::{Receiver, RecvError, SendError, Sender};
+
+use super::{RECV_PANIC_MESSAGE, SEND_PANIC_MESSAGE};
+use crate::{ChannelClosed, RecvOr, Share};
+
+impl<T> Share for Sender<T> {}
+impl<T> Share for Receiver<T> {}
+
+impl<T> ChannelClosed for SendError<T> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl ChannelClosed for RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> RecvOr for Result<T, RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|RecvError::Disconnected| f())
+    }
+}
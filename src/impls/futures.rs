@@ -0,0 +1,23 @@
+use futures::{FutureExt, Stream, future::Shared};
+
+use crate::{Share, ShareFuture, ShareStream, shared::SharedStream};
+
+impl<F: Future> Share for Shared<F> where F::Output: Clone {}
+
+impl<F: Future> ShareFuture for F {
+    fn share_future(self) -> Shared<F>
+    where
+        F::Output: Clone,
+    {
+        self.shared()
+    }
+}
+
+impl<S: Stream> ShareStream for S {
+    fn share_stream(self) -> SharedStream<S>
+    where
+        S::Item: Clone,
+    {
+        SharedStream::new(self)
+    }
+}
@@ -0,0 +1,16 @@
+use generational_arena::{Arena, Index};
+
+use super::MISSING_PANIC_MESSAGE;
+use crate::GetOr;
+
+impl<T> GetOr<Index> for Arena<T> {
+    type Value = T;
+
+    #[track_caller]
+    fn get_or_missing(&self, key: &Index) -> &T {
+        match self.get(*key) {
+            Some(value) => value,
+            None => panic!("{MISSING_PANIC_MESSAGE}"),
+        }
+    }
+}
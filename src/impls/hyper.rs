@@ -0,0 +1,6 @@
+use hyper_util::client::legacy::Client;
+
+use crate::Share;
+
+// clones share the same connection pool.
+impl<C: Clone, B> Share for Client<C, B> {}
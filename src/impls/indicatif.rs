@@ -0,0 +1,32 @@
+//! Progress bars share their state when cloned, so a bar can be
+//! updated from many threads at once.
+//!
+//! ```
+//! use std::thread;
+//! use indicatif::ProgressBar;
+//! use semtraits::Share;
+//!
+//! let bar = ProgressBar::hidden();
+//! bar.set_length(20);
+//! let handles: Vec<_> = (0..2)
+//!     .map(|_| {
+//!         let bar = bar.share();
+//!         thread::spawn(move || {
+//!             for _ in 0..10 {
+//!                 bar.inc(1);
+//!             }
+//!         })
+//!     })
+//!     .collect();
+//! for handle in handles {
+//!     handle.join().unwrap();
+//! }
+//! assert_eq!(bar.position(), 20);
+//! ```
+
+use indicatif::{MultiProgress, ProgressBar};
+
+use crate::Share;
+
+impl Share for ProgressBar {}
+impl Share for MultiProgress {}
@@ -0,0 +1,50 @@
+//! A left-right [`ReadHandle`] can't be sent to another thread unless
+//! the data is `Sync`, so threads should each take a handle from a
+//! shared [`ReadHandleFactory`] instead.
+//!
+//! ```
+//! use std::thread;
+//! use semtraits::{Share, Snapshot};
+//!
+//! # #[derive(Clone, Default)]
+//! # struct Log(Vec<&'static str>);
+//! # impl left_right::Absorb<&'static str> for Log {
+//! #     fn absorb_first(&mut self, line: &mut &'static str, _: &Self) {
+//! #         self.0.push(line);
+//! #     }
+//! #     fn sync_with(&mut self, first: &Self) {
+//! #         self.0 = first.0.clone();
+//! #     }
+//! # }
+//! let (mut write, read) = left_right::new::<Log, &'static str>();
+//! write.append("started").publish();
+//!
+//! let factory = read.factory();
+//! let reader = thread::spawn({
+//!     let factory = factory.share();
+//!     move || factory.handle().snapshot().unwrap().0.len()
+//! });
+//! assert_eq!(reader.join().unwrap(), 1);
+//! ```
+
+use left_right::{ReadGuard, ReadHandle, ReadHandleFactory};
+
+use crate::{Share, Snapshot};
+
+impl<T> Share for ReadHandle<T> {}
+impl<T> Share for ReadHandleFactory<T> {}
+
+impl<T> Snapshot for ReadHandle<T> {
+    type Target<'a>
+        = T
+    where
+        T: 'a;
+    type Guard<'a>
+        = ReadGuard<'a, T>
+    where
+        T: 'a;
+
+    fn snapshot(&self) -> Option<ReadGuard<'_, T>> {
+        self.enter()
+    }
+}
@@ -0,0 +1,7 @@
+use mlua::{Lua, WeakLua};
+
+use crate::Share;
+
+// clones refer to the same Lua state.
+impl Share for Lua {}
+impl Share for WeakLua {}
@@ -0,0 +1,214 @@
+//! Trait implementations on std and other common crates.
+
+use core::cell::{BorrowError, BorrowMutError};
+use core::fmt::{self, Debug};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::Ordering;
+
+#[cfg(target_has_atomic = "ptr")]
+use crate::Counter;
+#[cfg(target_has_atomic = "8")]
+use crate::SharedAtomic;
+use crate::{ChannelClosed, ClosedWhen, OrBorrowed, OrClosed, sealed};
+
+pub(crate) const SEND_PANIC_MESSAGE: &str = "sending with disconnected receiver";
+pub(crate) const RECV_PANIC_MESSAGE: &str = "receiving with no senders";
+#[cfg(feature = "std")]
+const POISON_PANIC_MESSAGE: &str = "lock poisoned";
+const CLOSED_PANIC_MESSAGE: &str = "channel closed";
+const BORROW_PANIC_MESSAGE: &str = "already mutably borrowed";
+const BORROW_MUT_PANIC_MESSAGE: &str = "already borrowed";
+#[cfg(any(feature = "std", feature = "slotmap", feature = "generational-arena"))]
+const MISSING_PANIC_MESSAGE: &str = "key not present";
+#[cfg(feature = "std")]
+const FULL_PANIC_MESSAGE: &str = "channel full";
+#[cfg(feature = "rtrb")]
+const EMPTY_PANIC_MESSAGE: &str = "channel empty";
+#[cfg(feature = "std")]
+const TIMEOUT_PANIC_MESSAGE: &str = "operation timed out";
+#[cfg(feature = "tokio")]
+const CONTENDED_PANIC_MESSAGE: &str = "lock contended";
+#[cfg(feature = "std")]
+const ALREADY_SET_PANIC_MESSAGE: &str = "cell already initialized";
+#[cfg(feature = "tokio")]
+const INITIALIZING_PANIC_MESSAGE: &str = "cell is being initialized";
+#[cfg(feature = "tokio")]
+const TASK_PANIC_MESSAGE: &str = "task panicked";
+#[cfg(feature = "tokio")]
+pub(crate) const SEMAPHORE_CLOSED_PANIC_MESSAGE: &str = "semaphore closed";
+#[cfg(feature = "tracing-subscriber")]
+const DROPPED_PANIC_MESSAGE: &str = "reloading a dropped subscriber";
+
+/// Fails on a closed channel or poisoned lock.
+///
+/// This panics, or prints `msg` and aborts the process if the
+/// `abort-on-closed` feature is enabled.
+#[cold]
+#[track_caller]
+pub(crate) fn fail(msg: fmt::Arguments<'_>) -> ! {
+    #[cfg(feature = "abort-on-closed")]
+    {
+        ::std::eprintln!("{msg}");
+        ::std::process::abort()
+    }
+    #[cfg(not(feature = "abort-on-closed"))]
+    panic!("{msg}")
+}
+
+impl<T, E: ChannelClosed> sealed::Sealed for Result<T, E> {}
+
+impl<T, E: ChannelClosed> OrClosed for Result<T, E> {
+    type Value = T;
+
+    fn check_open(self) -> Result<T, &'static str> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                let msg = e.describe_closed();
+                // send errors can hold an unsent value that is holding onto
+                // resources, release them before unwinding.
+                drop(e);
+                Err(msg)
+            }
+        }
+    }
+}
+
+impl<T, E: Debug> ClosedWhen for Result<T, E> {
+    type Value = T;
+    type Error = E;
+
+    #[track_caller]
+    fn or_closed_when(self, is_closed: impl FnOnce(&E) -> bool) -> T {
+        if let Err(e) = &self
+            && is_closed(e)
+        {
+            fail(format_args!("{CLOSED_PANIC_MESSAGE}"));
+        }
+        self.unwrap()
+    }
+}
+
+impl<T> OrBorrowed for Result<T, BorrowError> {
+    type Value = T;
+
+    #[track_caller]
+    fn or_borrowed(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(_) => panic!("{BORROW_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl<T> OrBorrowed for Result<T, BorrowMutError> {
+    type Value = T;
+
+    #[track_caller]
+    fn or_borrowed(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(_) => panic!("{BORROW_MUT_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl Counter for AtomicUsize {
+    fn bump(&self) -> usize {
+        self.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn try_bump(&self) -> Option<usize> {
+        self.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_add(1))
+            .ok()
+    }
+
+    fn current(&self) -> usize {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+macro_rules! impl_shared_atomic {
+    ($($width:literal => $($atomic:ident($value:ty)),*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl SharedAtomic for core::sync::atomic::$atomic {
+                type Value = $value;
+
+                fn get(&self) -> $value {
+                    self.load(Ordering::Relaxed)
+                }
+
+                fn set(&self, value: $value) {
+                    self.store(value, Ordering::Relaxed);
+                }
+
+                fn swap_in(&self, value: $value) -> $value {
+                    self.swap(value, Ordering::Relaxed)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_shared_atomic! {
+    "8" => AtomicBool(bool), AtomicU8(u8), AtomicI8(i8);
+    "16" => AtomicU16(u16), AtomicI16(i16);
+    "32" => AtomicU32(u32), AtomicI32(i32);
+    "64" => AtomicU64(u64), AtomicI64(i64);
+    "ptr" => AtomicUsize(usize), AtomicIsize(isize);
+}
+
+#[cfg(feature = "async-channel")]
+mod async_channel;
+#[cfg(feature = "async-io")]
+mod async_io;
+#[cfg(feature = "compio")]
+mod compio;
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel;
+#[cfg(feature = "evmap")]
+mod evmap;
+#[cfg(feature = "flume")]
+mod flume;
+#[cfg(feature = "futures")]
+mod futures;
+#[cfg(feature = "generational-arena")]
+mod generational_arena;
+#[cfg(feature = "hyper")]
+mod hyper;
+#[cfg(feature = "indicatif")]
+mod indicatif;
+#[cfg(feature = "left-right")]
+mod left_right;
+#[cfg(feature = "mlua")]
+mod mlua;
+#[cfg(feature = "notify")]
+mod notify;
+#[cfg(feature = "parking_lot")]
+mod parking_lot;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rtrb")]
+mod rtrb;
+#[cfg(feature = "slotmap")]
+mod slotmap;
+#[cfg(feature = "smol")]
+mod smol;
+#[cfg(feature = "std")]
+mod std;
+#[cfg(feature = "thread-local")]
+mod thread_local;
+#[cfg(feature = "tokio")]
+mod tokio;
+#[cfg(feature = "tokio-stream")]
+mod tokio_stream;
+#[cfg(feature = "tokio-util")]
+mod tokio_util;
+#[cfg(feature = "tracing-subscriber")]
+mod tracing_subscriber;
+#[cfg(feature = "triple-buffer")]
+mod triple_buffer;
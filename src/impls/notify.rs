@@ -0,0 +1,24 @@
+//! Watchers themselves are owned uniquely, but they deliver events through
+//! an [`EventHandler`](notify::EventHandler), which can be a std channel
+//! sender. Hand the watcher a shared sender to keep the channel open for
+//! other producers, and use [`or_closed`](crate::OrClosed::or_closed) on
+//! the receiving end as with any other channel.
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use std::sync::mpsc;
+//! use notify::{RecursiveMode, Watcher};
+//! use semtraits::{OrClosed, Share};
+//!
+//! let (tx, rx) = mpsc::channel();
+//! let mut watcher = notify::recommended_watcher(tx.share()).unwrap();
+//! watcher.watch(Path::new("."), RecursiveMode::Recursive).unwrap();
+//!
+//! // events keep arriving even if the watcher is dropped, since `tx`
+//! // still holds the channel open.
+//! let event = rx.recv().or_closed().unwrap();
+//! println!("{event:?}");
+//! ```
+//!
+//! No impls are needed here: the existing std channel impls
+//! already cover this.
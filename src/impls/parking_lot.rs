@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use parking_lot::{
+    ArcMutexGuard, Mutex, MutexGuard, RawMutex, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+
+use crate::{
+    LockArc,
+    lock::{Lock, ReadWriteLock},
+};
+
+impl<T: ?Sized> Lock for Mutex<T> {
+    type Target = T;
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        Mutex::lock(self)
+    }
+}
+
+impl<T: ?Sized> ReadWriteLock for RwLock<T> {
+    type Target = T;
+    type ReadGuard<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+    type WriteGuard<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        T: 'a;
+
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> LockArc for Arc<Mutex<T>> {
+    type Target = T;
+
+    fn lock_arc(&self) -> ArcMutexGuard<RawMutex, T> {
+        Mutex::lock_arc(self)
+    }
+}
@@ -0,0 +1,9 @@
+use rayon::ThreadPool;
+
+use crate::spawn::Spawn;
+
+impl<F: FnOnce() + Send + 'static> Spawn<F> for ThreadPool {
+    fn spawn_detached(&self, task: F) {
+        self.spawn(task);
+    }
+}
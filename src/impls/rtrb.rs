@@ -0,0 +1,28 @@
+//! An rtrb ring buffer has exactly one producer and one consumer, so
+//! neither half is [`Share`](crate::Share).
+
+use rtrb::{Consumer, PopError, Producer, PushError};
+
+use super::{EMPTY_PANIC_MESSAGE, FULL_PANIC_MESSAGE};
+use crate::{Pop, Push};
+
+impl<T> Push for Producer<T> {
+    type Item = T;
+
+    fn push_or_full(&mut self, value: T) {
+        if let Err(PushError::Full(_)) = self.push(value) {
+            panic!("{FULL_PANIC_MESSAGE}");
+        }
+    }
+}
+
+impl<T> Pop for Consumer<T> {
+    type Item = T;
+
+    fn pop_or_empty(&mut self) -> T {
+        match self.pop() {
+            Ok(value) => value,
+            Err(PopError::Empty) => panic!("{EMPTY_PANIC_MESSAGE}"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use slotmap::{DenseSlotMap, Key, SlotMap};
+
+use super::MISSING_PANIC_MESSAGE;
+use crate::GetOr;
+
+macro_rules! impl_get_or {
+    ($($map:ident),*) => {
+        $(
+            impl<K: Key, V> GetOr<K> for $map<K, V> {
+                type Value = V;
+
+                #[track_caller]
+                fn get_or_missing(&self, key: &K) -> &V {
+                    match self.get(*key) {
+                        Some(value) => value,
+                        None => panic!("{MISSING_PANIC_MESSAGE}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_get_or!(SlotMap, DenseSlotMap);
@@ -0,0 +1,14 @@
+use smol::Executor;
+
+use crate::spawn::Spawn;
+
+impl<'a, F> Spawn<F> for Executor<'a>
+where
+    F: Future + Send + 'a,
+    F::Output: Send + 'a,
+{
+    fn spawn_detached(&self, task: F) {
+        // smol cancels tasks when they are dropped, unlike most runtimes.
+        self.spawn(task).detach();
+    }
+}
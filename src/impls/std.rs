@@ -0,0 +1,252 @@
+use core::{
+    any::Any,
+    borrow::Borrow,
+    hash::{BuildHasher, Hash},
+};
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io,
+    net::{TcpListener, TcpStream, UdpSocket},
+    panic,
+    rc::{self, Rc},
+    sync::{
+        self, Arc, LockResult, Mutex, MutexGuard, OnceLock, RwLock, RwLockReadGuard,
+        RwLockWriteGuard,
+        mpsc::{RecvError, RecvTimeoutError, SendError, Sender, SyncSender, TrySendError},
+    },
+    thread,
+};
+
+use super::{
+    ALREADY_SET_PANIC_MESSAGE, FULL_PANIC_MESSAGE, MISSING_PANIC_MESSAGE, POISON_PANIC_MESSAGE,
+    RECV_PANIC_MESSAGE, SEND_PANIC_MESSAGE, TIMEOUT_PANIC_MESSAGE, fail,
+};
+use crate::{
+    ChannelClosed, CloneFd, GetOr, GetOrTryInit, IntoUnsent, OrFull, OrPanicked, OrPoisoned,
+    OrTimeout, RecvOr, SendOr, SendOrDrop, SetOr, Share, SharedCount, SplitClone,
+    lock::{Lock, ReadWriteLock},
+    spawn::{Spawn, StdThreads},
+};
+
+impl<T> Share for Rc<T> {}
+impl<T> Share for Arc<T> {}
+impl<T> Share for rc::Weak<T> {}
+impl<T> Share for sync::Weak<T> {}
+impl<T> Share for Sender<T> {}
+impl<T> Share for SyncSender<T> {}
+
+impl<T: ?Sized> SharedCount for Rc<T> {
+    fn is_unique(&self) -> bool {
+        Rc::strong_count(self) == 1 && Rc::weak_count(self) == 0
+    }
+}
+
+impl<T: ?Sized> SharedCount for Arc<T> {
+    fn is_unique(&self) -> bool {
+        Arc::strong_count(self) == 1 && Arc::weak_count(self) == 0
+    }
+}
+
+impl<T> ChannelClosed for SendError<T> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl ChannelClosed for RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> RecvOr for Result<T, RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|RecvError| f())
+    }
+}
+
+impl<T> OrTimeout for Result<T, RecvTimeoutError> {
+    type Value = T;
+
+    fn or_timeout(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(RecvTimeoutError::Timeout) => panic!("{TIMEOUT_PANIC_MESSAGE}"),
+            Err(RecvTimeoutError::Disconnected) => fail(format_args!("{RECV_PANIC_MESSAGE}")),
+        }
+    }
+}
+
+impl<T> SendOr for Result<(), SendError<T>> {
+    type Unsent = T;
+
+    fn send_or(self, f: impl FnOnce(T)) -> Result<(), ()> {
+        self.map_err(|SendError(unsent)| f(unsent))
+    }
+}
+
+impl<T> IntoUnsent for Result<(), TrySendError<T>> {
+    type Unsent = T;
+
+    fn into_unsent(self) -> Result<(), T> {
+        self.map_err(|(TrySendError::Full(unsent) | TrySendError::Disconnected(unsent))| unsent)
+    }
+}
+
+impl<T> OrFull for Result<(), TrySendError<T>> {
+    fn or_full(self) {
+        match self {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => panic!("{FULL_PANIC_MESSAGE}"),
+            Err(TrySendError::Disconnected(_)) => fail(format_args!("{SEND_PANIC_MESSAGE}")),
+        }
+    }
+}
+
+impl<T> SendOrDrop for SyncSender<T> {
+    type Item = T;
+
+    fn try_send_or_drop(&self, value: T) -> bool {
+        self.try_send(value).is_ok()
+    }
+}
+
+impl<T> OrPoisoned for LockResult<T> {
+    type Value = T;
+
+    fn or_poisoned(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(e) => fail(format_args!("{POISON_PANIC_MESSAGE}: {e:?}")),
+        }
+    }
+}
+
+impl<T> OrPanicked for thread::Result<T> {
+    type Value = T;
+
+    fn or_panicked(self) -> T {
+        self.unwrap_or_else(|payload: Box<dyn Any + Send>| panic::resume_unwind(payload))
+    }
+}
+
+impl<T: ?Sized> Lock for Mutex<T> {
+    type Target = T;
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        Mutex::lock(self).or_poisoned()
+    }
+}
+
+impl<T: ?Sized> ReadWriteLock for RwLock<T> {
+    type Target = T;
+    type ReadGuard<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+    type WriteGuard<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        T: 'a;
+
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).or_poisoned()
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).or_poisoned()
+    }
+}
+
+macro_rules! impl_clone_fd {
+    ($($ty:ty),*) => {
+        $(
+            impl CloneFd for $ty {
+                fn clone_fd(&self) -> io::Result<Self> {
+                    self.try_clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_fd!(File, TcpListener, TcpStream, UdpSocket);
+
+impl SplitClone for TcpStream {}
+
+impl<K, Q, V, S> GetOr<Q> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Eq + Hash,
+    Q: Eq + Hash + ?Sized,
+    S: BuildHasher,
+{
+    type Value = V;
+
+    #[track_caller]
+    fn get_or_missing(&self, key: &Q) -> &V {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("{MISSING_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl<K, Q, V> GetOr<Q> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    type Value = V;
+
+    #[track_caller]
+    fn get_or_missing(&self, key: &Q) -> &V {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("{MISSING_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl<T> GetOrTryInit for OnceLock<T> {
+    type Value = T;
+
+    fn or_try_init<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<&T, E> {
+        if let Some(value) = self.get() {
+            return Ok(value);
+        }
+        // `OnceLock::get_or_try_init` is unstable, so the closure is run
+        // outside of the cell and can race with other initializers.
+        let value = f()?;
+        Ok(self.get_or_init(|| value))
+    }
+}
+
+impl<T> SetOr for OnceLock<T> {
+    type Value = T;
+
+    fn set_or_already_set(&self, value: T) {
+        // checked rather than unwrapped, so `T` doesn't need to be `Debug`.
+        if self.set(value).is_err() {
+            panic!("{ALREADY_SET_PANIC_MESSAGE}");
+        }
+    }
+}
+
+impl<F, T> Spawn<F> for StdThreads
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    fn spawn_detached(&self, task: F) {
+        // dropping the join handle detaches the thread.
+        thread::spawn(task);
+    }
+}
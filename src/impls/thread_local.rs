@@ -0,0 +1,11 @@
+use thread_local::ThreadLocal;
+
+use crate::PerThread;
+
+impl<T: Send> PerThread for ThreadLocal<T> {
+    type Value = T;
+
+    fn per_thread(&self, f: impl FnOnce() -> T) -> &T {
+        self.get_or(f)
+    }
+}
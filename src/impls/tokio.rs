@@ -0,0 +1,560 @@
+//! [`blocking_send`] from sync code returns the same error as the async
+//! `send`, so `or_closed` covers both.
+//!
+//! ```
+//! use tokio::sync::mpsc;
+//! use semtraits::OrClosed;
+//!
+//! let (tx, mut rx) = mpsc::channel(1);
+//! tx.blocking_send(10).or_closed();
+//! assert_eq!(rx.blocking_recv(), Some(10));
+//!
+//! drop(rx);
+//! # #[cfg(not(feature = "abort-on-closed"))]
+//! # {
+//! let result = std::panic::catch_unwind(|| tx.blocking_send(20).or_closed());
+//! assert!(result.is_err());
+//! # }
+//! ```
+//!
+//! Sharing a sender keeps the channel open until every share has been
+//! dropped.
+//!
+//! ```
+//! use tokio::sync::mpsc;
+//! use semtraits::{OrClosed, Share};
+//!
+//! let (tx, mut rx) = mpsc::unbounded_channel();
+//! let tx1 = tx.share();
+//! drop(tx);
+//! tx1.send(10).or_closed();
+//! assert_eq!(rx.blocking_recv(), Some(10));
+//!
+//! drop(tx1);
+//! assert_eq!(rx.blocking_recv(), None);
+//! ```
+//!
+//! A watch sender can only fail to send once every receiver has been
+//! dropped, after which the channel is closed for good.
+//!
+//! ```
+//! use tokio::sync::watch;
+//! use semtraits::{OrClosed, Share};
+//!
+//! let (tx, rx) = watch::channel(0);
+//! let rx1 = rx.share();
+//! tx.send(1).or_closed();
+//! assert_eq!(*rx1.borrow(), 1);
+//!
+//! drop(rx);
+//! drop(rx1);
+//! # #[cfg(not(feature = "abort-on-closed"))]
+//! # {
+//! let result = std::panic::catch_unwind(|| tx.send(2).or_closed());
+//! assert!(result.is_err());
+//! # }
+//! ```
+//!
+//! [`blocking_send`]: mpsc::Sender::blocking_send
+
+use std::{sync::Arc, vec::Vec};
+
+use tokio::{
+    net::{
+        TcpStream,
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+    },
+    runtime::{Handle, Runtime},
+    sync::{
+        Mutex, MutexGuard, OnceCell, OwnedMutexGuard, OwnedRwLockReadGuard, OwnedRwLockWriteGuard,
+        RwLock, RwLockReadGuard, RwLockWriteGuard, SetError, broadcast, mpsc, oneshot, watch,
+    },
+    task::{JoinError, JoinSet},
+    time::{Instant, Interval, error::Elapsed},
+};
+
+use super::{
+    ALREADY_SET_PANIC_MESSAGE, CONTENDED_PANIC_MESSAGE, FULL_PANIC_MESSAGE,
+    INITIALIZING_PANIC_MESSAGE, RECV_PANIC_MESSAGE, SEND_PANIC_MESSAGE, TASK_PANIC_MESSAGE,
+    TIMEOUT_PANIC_MESSAGE, fail,
+};
+use crate::{
+    AsyncGetOrTryInit, AwaitClosed, BlockOn, BlockingLock, BroadcastSend, ChannelClosed,
+    ChannelMetrics, Drain, IntoUnsent, JoinAll, LockOwned, OrAlreadySet, OrClosed, OrPanicked,
+    OrPoisoned, OrTimeout, ReadWriteOwned, ReceiverCount, RecvLatest, RecvMany, RecvOr,
+    RecvSkipLagged, Reserve, SendIfChanged, SendOr, SendOrDrop, SetOr, Share, Split, Tick,
+    TryReadWrite,
+    lock::{AsyncLock, AsyncReadWriteLock},
+    spawn::Spawn,
+};
+
+impl<T> Share for mpsc::Sender<T> {}
+impl<T> Share for mpsc::UnboundedSender<T> {}
+impl<T> Share for mpsc::WeakSender<T> {}
+impl<T> Share for mpsc::WeakUnboundedSender<T> {}
+impl<T> Share for broadcast::Sender<T> {}
+impl<T> Share for watch::Sender<T> {}
+impl<T> Share for watch::Receiver<T> {}
+
+impl<T: Send + Sync> AwaitClosed for watch::Sender<T> {
+    async fn await_closed(&self) {
+        self.closed().await;
+    }
+}
+
+impl<T: PartialEq> SendIfChanged for watch::Sender<T> {
+    type Item = T;
+
+    fn send_if_changed(&self, value: T) -> bool {
+        self.send_if_modified(|current| {
+            if *current == value {
+                return false;
+            }
+            *current = value;
+            true
+        })
+    }
+}
+
+impl<T> ReceiverCount for watch::Sender<T> {
+    fn receiver_count(&self) -> usize {
+        watch::Sender::receiver_count(self)
+    }
+}
+
+impl<T> ChannelMetrics for mpsc::Sender<T> {
+    fn queued(&self) -> usize {
+        self.max_capacity() - self.capacity()
+    }
+
+    fn free_slots(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T> ChannelMetrics for mpsc::Receiver<T> {
+    fn queued(&self) -> usize {
+        // `len` leaves out reserved slots, which the sender can't tell
+        // apart from sent values.
+        self.max_capacity() - self.capacity()
+    }
+
+    fn free_slots(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<E> ChannelClosed for mpsc::error::SendError<E> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl<T: Send> AwaitClosed for mpsc::Sender<T> {
+    async fn await_closed(&self) {
+        self.closed().await;
+    }
+}
+
+impl<T: Send> AwaitClosed for mpsc::UnboundedSender<T> {
+    async fn await_closed(&self) {
+        self.closed().await;
+    }
+}
+
+impl<T> SendOr for Result<(), mpsc::error::SendError<T>> {
+    type Unsent = T;
+
+    fn send_or(self, f: impl FnOnce(T)) -> Result<(), ()> {
+        self.map_err(|mpsc::error::SendError(unsent)| f(unsent))
+    }
+}
+
+impl<T> IntoUnsent for Result<(), mpsc::error::TrySendError<T>> {
+    type Unsent = T;
+
+    fn into_unsent(self) -> Result<(), T> {
+        use mpsc::error::TrySendError::{Closed, Full};
+        self.map_err(|(Full(unsent) | Closed(unsent))| unsent)
+    }
+}
+
+// mpsc recv returns an Option instead of Result :(
+
+impl<T: Send> RecvMany for mpsc::Receiver<T> {
+    type Item = T;
+
+    async fn recv_many_or_closed(&mut self, buf: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.recv_many(buf, limit).await;
+        if received == 0 && limit != 0 {
+            fail(format_args!("{RECV_PANIC_MESSAGE}"));
+        }
+        received
+    }
+}
+
+impl<T: Send> RecvMany for mpsc::UnboundedReceiver<T> {
+    type Item = T;
+
+    async fn recv_many_or_closed(&mut self, buf: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.recv_many(buf, limit).await;
+        if received == 0 && limit != 0 {
+            fail(format_args!("{RECV_PANIC_MESSAGE}"));
+        }
+        received
+    }
+}
+
+impl<T> SendOrDrop for mpsc::Sender<T> {
+    type Item = T;
+
+    fn try_send_or_drop(&self, value: T) -> bool {
+        self.try_send(value).is_ok()
+    }
+}
+
+impl<T: Send> Reserve for mpsc::Sender<T> {
+    type Permit<'a>
+        = mpsc::Permit<'a, T>
+    where
+        T: 'a;
+
+    async fn reserve_or_closed(&self) -> mpsc::Permit<'_, T> {
+        self.reserve().await.or_closed()
+    }
+
+    fn try_reserve_or_full(&self) -> mpsc::Permit<'_, T> {
+        match self.try_reserve() {
+            Ok(permit) => permit,
+            Err(mpsc::error::TrySendError::Full(())) => panic!("{FULL_PANIC_MESSAGE}"),
+            Err(mpsc::error::TrySendError::Closed(())) => {
+                fail(format_args!("{SEND_PANIC_MESSAGE}"))
+            }
+        }
+    }
+}
+
+impl<T: Send> Drain for mpsc::Receiver<T> {
+    type Item = T;
+
+    async fn drain(&mut self) -> Vec<T> {
+        let mut values = Vec::new();
+        while let Some(value) = self.recv().await {
+            values.push(value);
+        }
+        values
+    }
+}
+
+impl<T: Send> Drain for mpsc::UnboundedReceiver<T> {
+    type Item = T;
+
+    async fn drain(&mut self) -> Vec<T> {
+        let mut values = Vec::new();
+        while let Some(value) = self.recv().await {
+            values.push(value);
+        }
+        values
+    }
+}
+
+impl<E> ChannelClosed for watch::error::SendError<E> {
+    fn describe_closed(&self) -> &'static str {
+        SEND_PANIC_MESSAGE
+    }
+}
+
+impl ChannelClosed for watch::error::RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> RecvOr for Result<T, watch::error::RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|_| f())
+    }
+}
+
+// oneshot send returns a Result<(), T> :(
+
+impl ChannelClosed for oneshot::error::RecvError {
+    fn describe_closed(&self) -> &'static str {
+        RECV_PANIC_MESSAGE
+    }
+}
+
+impl<T> RecvOr for Result<T, oneshot::error::RecvError> {
+    type Value = T;
+
+    fn recv_or(self, f: impl FnOnce() -> T) -> T {
+        self.unwrap_or_else(|_| f())
+    }
+}
+
+impl<T: ?Sized> TryReadWrite for RwLock<T> {
+    type Target = T;
+
+    #[track_caller]
+    fn try_read_or_contended(&self) -> RwLockReadGuard<'_, T> {
+        match self.try_read() {
+            Ok(guard) => guard,
+            Err(_) => panic!("{CONTENDED_PANIC_MESSAGE}"),
+        }
+    }
+
+    #[track_caller]
+    fn try_write_or_contended(&self) -> RwLockWriteGuard<'_, T> {
+        match self.try_write() {
+            Ok(guard) => guard,
+            Err(_) => panic!("{CONTENDED_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl<T: ?Sized> BlockingLock for Mutex<T> {
+    type Target = T;
+
+    fn lock_blocking(&self) -> MutexGuard<'_, T> {
+        Mutex::blocking_lock(self)
+    }
+}
+
+// tokio locks release on panic instead of poisoning.
+impl<'a, T: ?Sized> OrPoisoned for MutexGuard<'a, T> {
+    type Value = MutexGuard<'a, T>;
+
+    fn or_poisoned(self) -> Self {
+        self
+    }
+}
+
+impl<T: ?Sized> OrPoisoned for OwnedMutexGuard<T> {
+    type Value = OwnedMutexGuard<T>;
+
+    fn or_poisoned(self) -> Self {
+        self
+    }
+}
+
+impl<T: ?Sized + Send> LockOwned for Arc<Mutex<T>> {
+    type Target = T;
+
+    async fn lock_owned(self) -> OwnedMutexGuard<T> {
+        Mutex::lock_owned(self).await
+    }
+}
+
+impl<T: ?Sized + Send + Sync> ReadWriteOwned for Arc<RwLock<T>> {
+    type Target = T;
+
+    async fn read_owned(self) -> OwnedRwLockReadGuard<T> {
+        RwLock::read_owned(self).await
+    }
+
+    async fn write_owned(self) -> OwnedRwLockWriteGuard<T> {
+        RwLock::write_owned(self).await
+    }
+}
+
+impl<T: Send + Sync> AsyncGetOrTryInit for OnceCell<T> {
+    type Value = T;
+
+    async fn or_try_init<E, F, Fut>(&self, f: F) -> Result<&T, E>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<T, E>> + Send,
+    {
+        self.get_or_try_init(f).await
+    }
+}
+
+impl<T> OrAlreadySet for Result<(), SetError<T>> {
+    type Value = ();
+
+    #[track_caller]
+    fn or_already_set(self) {
+        // matched rather than unwrapped, so `T` doesn't need to be `Debug`.
+        match self {
+            Ok(()) => {}
+            Err(SetError::AlreadyInitializedError(_)) => panic!("{ALREADY_SET_PANIC_MESSAGE}"),
+            Err(SetError::InitializingError(_)) => panic!("{INITIALIZING_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl<T> SetOr for OnceCell<T> {
+    type Value = T;
+
+    fn set_or_already_set(&self, value: T) {
+        self.set(value).or_already_set();
+    }
+}
+
+impl<T: ?Sized + Send> AsyncLock for Mutex<T> {
+    type Target = T;
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    async fn lock(&self) -> MutexGuard<'_, T> {
+        Mutex::lock(self).await
+    }
+}
+
+impl<T: ?Sized + Send + Sync> AsyncReadWriteLock for RwLock<T> {
+    type Target = T;
+    type ReadGuard<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+    type WriteGuard<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        T: 'a;
+
+    async fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).await
+    }
+
+    async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).await
+    }
+}
+
+impl<T> OrTimeout for Result<T, Elapsed> {
+    type Value = T;
+
+    fn or_timeout(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(_) => panic!("{TIMEOUT_PANIC_MESSAGE}"),
+        }
+    }
+}
+
+impl Tick for Interval {
+    async fn tick_now(&mut self) -> Instant {
+        self.tick().await
+    }
+
+    fn reset_interval(&mut self) {
+        self.reset();
+    }
+}
+
+impl<T> BroadcastSend for broadcast::Sender<T> {
+    type Item = T;
+
+    fn send_to_any(&self, value: T) -> Option<usize> {
+        self.send(value).ok()
+    }
+}
+
+impl<T: Clone + Send> RecvSkipLagged for broadcast::Receiver<T> {
+    type Item = T;
+
+    async fn recv_skip_lagged(&mut self) -> Result<T, broadcast::error::RecvError> {
+        loop {
+            match self.recv().await {
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<T: Clone + Send> RecvLatest for broadcast::Receiver<T> {
+    type Item = T;
+
+    async fn recv_latest(&mut self) -> Result<T, broadcast::error::RecvError> {
+        use broadcast::error::TryRecvError;
+
+        let mut latest = self.recv_skip_lagged().await?;
+        loop {
+            match self.try_recv() {
+                Ok(value) => latest = value,
+                Err(TryRecvError::Lagged(_)) => continue,
+                // a close is reported on the next call, after `latest`.
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return Ok(latest),
+            }
+        }
+    }
+}
+
+impl BlockOn for Runtime {
+    fn block_on_future<F: Future>(&self, future: F) -> F::Output {
+        self.block_on(future)
+    }
+}
+
+impl BlockOn for Handle {
+    fn block_on_future<F: Future>(&self, future: F) -> F::Output {
+        self.block_on(future)
+    }
+}
+
+impl<T> OrPanicked for Result<T, JoinError> {
+    type Value = T;
+
+    #[track_caller]
+    fn or_panicked(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(e) => match e.try_into_panic() {
+                Ok(payload) => std::panic::resume_unwind(payload),
+                // cancelled, there is no panic to continue.
+                Err(e) => panic!("{e}"),
+            },
+        }
+    }
+}
+
+impl<T: Send + 'static> JoinAll for JoinSet<T> {
+    async fn await_all_or_panic(&mut self) {
+        while let Some(result) = self.join_next().await {
+            if let Err(e) = result
+                && e.is_panic()
+            {
+                panic!("{TASK_PANIC_MESSAGE}: {e}");
+            }
+        }
+    }
+}
+
+impl Split for TcpStream {
+    type ReadHalf = OwnedReadHalf;
+    type WriteHalf = OwnedWriteHalf;
+
+    fn split_owned(self) -> (OwnedReadHalf, OwnedWriteHalf) {
+        self.into_split()
+    }
+}
+
+impl<F> Spawn<F> for Runtime
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn spawn_detached(&self, task: F) {
+        self.spawn(task);
+    }
+}
+
+impl<F> Spawn<F> for Handle
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn spawn_detached(&self, task: F) {
+        self.spawn(task);
+    }
+}
+
+// `OrClosed` should not be implemented for broadcast channels.
+// recv error is Closed or Lagged, which should usually be handled manually.
+// sender could be subscribed to after trying to send to no receivers,
+// so it can return Ok after an Err.
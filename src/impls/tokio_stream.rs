@@ -0,0 +1,53 @@
+use tokio::sync::{broadcast, mpsc, watch};
+use tokio_stream::{
+    StreamExt,
+    wrappers::{BroadcastStream, ReceiverStream, UnboundedReceiverStream, WatchStream},
+};
+
+use crate::{IntoStream, SkipLagged};
+
+impl<T> IntoStream for mpsc::Receiver<T> {
+    type Stream = ReceiverStream<T>;
+
+    fn into_stream(self) -> ReceiverStream<T> {
+        ReceiverStream::new(self)
+    }
+}
+
+impl<T> IntoStream for mpsc::UnboundedReceiver<T> {
+    type Stream = UnboundedReceiverStream<T>;
+
+    fn into_stream(self) -> UnboundedReceiverStream<T> {
+        UnboundedReceiverStream::new(self)
+    }
+}
+
+/// Items are `Err` when the receiver lagged behind, see [`SkipLagged`]
+/// to ignore them instead.
+impl<T: Clone + Send + 'static> IntoStream for broadcast::Receiver<T> {
+    type Stream = BroadcastStream<T>;
+
+    fn into_stream(self) -> BroadcastStream<T> {
+        BroadcastStream::new(self)
+    }
+}
+
+/// The stream yields the current value first, then the latest value
+/// after each change. Values that are replaced before the stream is
+/// polled are never seen.
+impl<T: Clone + Send + Sync + 'static> IntoStream for watch::Receiver<T> {
+    type Stream = WatchStream<T>;
+
+    fn into_stream(self) -> WatchStream<T> {
+        WatchStream::new(self)
+    }
+}
+
+impl<T: Clone + Send + 'static> SkipLagged for BroadcastStream<T> {
+    type Item = T;
+
+    fn skip_lagged(self) -> impl tokio_stream::Stream<Item = T> + Send {
+        // lagging is the only error a broadcast stream yields.
+        self.filter_map(Result::ok)
+    }
+}
@@ -0,0 +1,12 @@
+use tokio::sync::mpsc;
+use tokio_util::sync::PollSender;
+
+use crate::IntoSink;
+
+impl<T: Send> IntoSink for mpsc::Sender<T> {
+    type Item = T;
+
+    fn into_sink(self) -> PollSender<T> {
+        PollSender::new(self)
+    }
+}
@@ -0,0 +1,19 @@
+use tracing_subscriber::reload::Handle;
+
+use super::{DROPPED_PANIC_MESSAGE, POISON_PANIC_MESSAGE, fail};
+use crate::{Reload, Share};
+
+impl<L, S> Share for Handle<L, S> {}
+
+impl<L, S> Reload for Handle<L, S> {
+    type Layer = L;
+
+    fn reload_layer(&self, new: L) {
+        if let Err(e) = self.reload(new) {
+            if e.is_poisoned() {
+                fail(format_args!("{POISON_PANIC_MESSAGE}"));
+            }
+            panic!("{DROPPED_PANIC_MESSAGE}");
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use triple_buffer::{Input, Output};
+
+use crate::{Peek, Publish};
+
+impl<T: Send> Publish for Input<T> {
+    type Item = T;
+
+    fn publish_value(&mut self, value: T) {
+        self.write(value);
+    }
+}
+
+impl<T: Send> Peek for Output<T> {
+    type Item = T;
+
+    fn latest(&mut self) -> &T {
+        self.read()
+    }
+}
@@ -8,7 +8,9 @@
 //! that you think should implement one of these traits, feel free
 //! to open an issue/PR.
 //!
-//! You can also implement these traits for your own types.
+//! You can also implement these traits for your own types, except for
+//! [`OrClosed`], which is sealed. A new channel gets `or_closed` by
+//! implementing [`ChannelClosed`] for its error type instead.
 //!
 //! # Examples
 //!
@@ -98,6 +100,20 @@
 //! assert_eq!(*mutex.lock().or_poisoned(), 12);
 //! ```
 //!
+//! ## Aborting
+//!
+//! With the `abort-on-closed` feature, a closed channel or poisoned lock
+//! prints the message to stderr and aborts the process instead of
+//! panicking. This is for services that would rather restart than keep
+//! running with a worker that has died, and it works the same whether
+//! or not the binary is built with `panic = "abort"`.
+//!
+//! The downside is that nothing gets to clean up: destructors on the
+//! current thread and every other thread are not run, and the failure
+//! can't be caught with [`catch_unwind`] or observed through a thread's
+//! join handle. Only the final binary should enable this feature.
+//!
+//! [`catch_unwind`]: std::panic::catch_unwind
 //! [`Rc`]: std::rc::Rc
 //! [`Arc`]: std::sync::Arc
 //! [`mpsc`]: std::sync::mpsc
@@ -107,7 +123,18 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod channel;
 mod impls;
+pub mod lock;
+pub mod shared;
+pub mod spawn;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+mod sealed {
+    /// Restricts [`OrClosed`](crate::OrClosed) to the impl in this crate.
+    pub trait Sealed {}
+}
 
 /// A cheap [`Clone`] with shared reference semantics.
 ///
@@ -142,6 +169,25 @@ mod impls;
 /// assert_eq!(rx.recv().unwrap(), 10);
 /// ```
 ///
+/// Types that implement [`Share`] don't need to be wrapped in an
+/// [`Arc`] to be shared, prefer calling `.share()` directly.
+///
+/// ```
+/// use std::sync::{Arc, mpsc};
+/// use semtraits::Share;
+///
+/// let (tx, rx) = mpsc::channel();
+/// // unnecessary, the sender already shares its channel.
+/// let arced = Arc::new(tx.share());
+/// // preferred.
+/// let shared = tx.share();
+///
+/// arced.send(1).unwrap();
+/// shared.send(2).unwrap();
+/// assert_eq!(rx.recv().unwrap(), 1);
+/// assert_eq!(rx.recv().unwrap(), 2);
+/// ```
+///
 /// [`Rc`]: std::rc::Rc
 /// [`Arc`]: std::sync::Arc
 /// [`mpsc`]: std::sync::mpsc
@@ -171,10 +217,599 @@ pub trait Share: Clone {
 /// This trait is implemented on the output of `tx.send()` and
 /// `rx.recv()` methods, which are generally [`Result`] types with
 /// a channel-specific error type.
-pub trait OrClosed {
+///
+/// A different panic message can be given for a specific call site
+/// with [`or_closed_with`](OrClosed::or_closed_with).
+///
+/// ```
+/// use std::panic;
+/// use std::sync::mpsc;
+/// use semtraits::OrClosed;
+///
+/// let (tx, rx) = mpsc::channel::<i32>();
+/// drop(tx);
+/// # #[cfg(not(feature = "abort-on-closed"))]
+/// # {
+/// let payload = panic::catch_unwind(|| rx.recv().or_closed_with("worker exited early"))
+///     .unwrap_err();
+/// assert_eq!(payload.downcast_ref::<String>().unwrap(), "worker exited early");
+/// # }
+/// ```
+///
+/// [`or_send_closed`](OrClosed::or_send_closed) and
+/// [`or_recv_closed`](OrClosed::or_recv_closed) say at the call site
+/// which side is expected to still be open, and always use the send or
+/// receive message.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::OrClosed;
+///
+/// let (tx, rx) = mpsc::channel();
+/// tx.send(1).or_send_closed();
+/// assert_eq!(rx.recv().or_recv_closed(), 1);
+/// ```
+///
+/// This is implemented for every [`Result`] whose error is a
+/// [`ChannelClosed`], which is the way to support other channels. It is
+/// sealed so that it can't be implemented any other way.
+///
+/// Errors that can also mean the channel is only temporarily empty or
+/// full don't get it, as panicking on them would be wrong:
+///
+/// ```compile_fail
+/// use std::sync::mpsc;
+/// use semtraits::OrClosed;
+///
+/// let (_tx, rx) = mpsc::channel::<i32>();
+/// rx.try_recv().or_closed(); // an empty channel isn't closed
+/// ```
+pub trait OrClosed: Sized + sealed::Sealed {
+    type Value;
+
+    /// Gets the value if the channel is open, or the default panic
+    /// message if it is closed.
+    fn check_open(self) -> Result<Self::Value, &'static str>;
+
+    #[track_caller]
+    fn or_closed(self) -> Self::Value {
+        match self.check_open() {
+            Ok(value) => value,
+            Err(msg) => impls::fail(format_args!("{msg}")),
+        }
+    }
+
+    #[track_caller]
+    fn or_closed_with(self, msg: &'static str) -> Self::Value {
+        match self.check_open() {
+            Ok(value) => value,
+            Err(_) => impls::fail(format_args!("{msg}")),
+        }
+    }
+
+    /// Like [`or_closed`](OrClosed::or_closed), for a send that expects
+    /// the receiver to still be alive.
+    #[track_caller]
+    fn or_send_closed(self) -> Self::Value {
+        self.or_closed_with(impls::SEND_PANIC_MESSAGE)
+    }
+
+    /// Like [`or_closed`](OrClosed::or_closed), for a receive that
+    /// expects a sender to still be alive.
+    #[track_caller]
+    fn or_recv_closed(self) -> Self::Value {
+        self.or_closed_with(impls::RECV_PANIC_MESSAGE)
+    }
+}
+
+/// A channel error that means the channel is closed.
+///
+/// Implementing this for an error type gives [`OrClosed`] to any
+/// [`Result`] with that error. Like [`OrClosed`], it should only be
+/// implemented on errors that are returned *if and only if* the channel
+/// can never send/receive again.
+///
+/// ```
+/// use std::panic;
+/// use semtraits::{ChannelClosed, OrClosed};
+///
+/// struct Hangup;
+///
+/// impl ChannelClosed for Hangup {
+///     fn describe_closed(&self) -> &'static str {
+///         "peer hung up"
+///     }
+/// }
+///
+/// let open: Result<i32, Hangup> = Ok(1);
+/// assert_eq!(open.or_closed(), 1);
+///
+/// let closed: Result<i32, Hangup> = Err(Hangup);
+/// # #[cfg(not(feature = "abort-on-closed"))]
+/// # {
+/// let payload = panic::catch_unwind(|| closed.or_closed()).unwrap_err();
+/// assert_eq!(payload.downcast_ref::<String>().unwrap(), "peer hung up");
+/// # }
+/// ```
+///
+/// The error is dropped before panicking, so any unsent value it holds
+/// is released first.
+pub trait ChannelClosed {
+    /// The panic message for [`or_closed`](OrClosed::or_closed).
+    fn describe_closed(&self) -> &'static str;
+}
+
+/// Panic when an error is one that indicates a closed channel.
+///
+/// This is for channels with custom error types, where only some
+/// of the errors mean that the channel is closed. The predicate
+/// decides whether an error is a closed channel, which panics with
+/// a "channel closed" message. Any other error is unwrapped.
+///
+/// ```
+/// use semtraits::ClosedWhen;
+///
+/// #[derive(Debug)]
+/// enum ChannelError {
+///     Closed,
+///     Corrupted,
+/// }
+///
+/// let ok: Result<i32, ChannelError> = Ok(10);
+/// assert_eq!(ok.or_closed_when(|e| matches!(e, ChannelError::Closed)), 10);
+///
+/// let closed: Result<i32, ChannelError> = Err(ChannelError::Closed);
+/// # #[cfg(not(feature = "abort-on-closed"))]
+/// # {
+/// let result = std::panic::catch_unwind(|| {
+///     closed.or_closed_when(|e| matches!(e, ChannelError::Closed))
+/// });
+/// assert!(result.is_err());
+/// # }
+/// ```
+///
+/// Errors that are not closed channels panic with their [`Debug`]
+/// representation, like [`Result::unwrap`].
+///
+/// ```should_panic
+/// # use semtraits::ClosedWhen;
+/// # #[derive(Debug)]
+/// # enum ChannelError {
+/// #     Closed,
+/// #     Corrupted,
+/// # }
+/// let corrupted: Result<i32, ChannelError> = Err(ChannelError::Corrupted);
+/// corrupted.or_closed_when(|e| matches!(e, ChannelError::Closed));
+/// ```
+pub trait ClosedWhen {
+    type Value;
+    type Error;
+
+    fn or_closed_when(self, is_closed: impl FnOnce(&Self::Error) -> bool) -> Self::Value;
+}
+
+/// Falls back to another value when receiving from a closed channel.
+///
+/// This is the non-panicking counterpart to [`OrClosed`], for receivers
+/// where the senders going away is an expected way for the channel to
+/// finish.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::RecvOr;
+///
+/// let (tx, rx) = mpsc::channel();
+/// tx.send(1).unwrap();
+/// drop(tx);
+/// assert_eq!(rx.recv().recv_or(|| 0), 1);
+/// assert_eq!(rx.recv().recv_or(|| 0), 0);
+/// ```
+pub trait RecvOr {
+    type Value;
+
+    fn recv_or(self, f: impl FnOnce() -> Self::Value) -> Self::Value;
+}
+
+/// Hands the unsent value to a closure when sending to a closed channel.
+///
+/// This is the send side of [`RecvOr`]: instead of panicking like
+/// [`OrClosed`], the value that couldn't be sent is passed to `f` so it
+/// can be queued somewhere else or logged.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::SendOr;
+///
+/// let (tx, rx) = mpsc::channel();
+/// drop(rx);
+/// let mut dead_letters = Vec::new();
+/// assert_eq!(tx.send("hello").send_or(|msg| dead_letters.push(msg)), Err(()));
+/// assert_eq!(dead_letters, ["hello"]);
+/// ```
+pub trait SendOr {
+    type Unsent;
+
+    /// Returns `Err(())` if the value was not sent, after passing it
+    /// to `f`.
+    #[allow(
+        clippy::result_unit_err,
+        reason = "the unsent value has been handed to `f`"
+    )]
+    fn send_or(self, f: impl FnOnce(Self::Unsent)) -> Result<(), ()>;
+}
+
+/// Gets back the value that failed to send.
+///
+/// Sending can fail for reasons other than the channel being closed,
+/// like a bounded channel being full. The unsent value is returned
+/// regardless of why it couldn't be sent, so that it can be retried
+/// or handled some other way.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::IntoUnsent;
+///
+/// let (tx, rx) = mpsc::sync_channel(1);
+/// assert_eq!(tx.try_send(1).into_unsent(), Ok(()));
+/// // full
+/// assert_eq!(tx.try_send(2).into_unsent(), Err(2));
+///
+/// drop(rx);
+/// // closed
+/// assert_eq!(tx.try_send(3).into_unsent(), Err(3));
+/// ```
+pub trait IntoUnsent {
+    type Unsent;
+
+    fn into_unsent(self) -> Result<(), Self::Unsent>;
+}
+
+/// Panics if a value couldn't be sent because a bounded channel is full.
+///
+/// This is for channels that are sized so that they should never fill
+/// up, where a full channel means the receiver has fallen behind. A
+/// closed channel panics like [`OrClosed`].
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::OrFull;
+///
+/// let (tx, rx) = mpsc::sync_channel(1);
+/// tx.try_send(1).or_full();
+/// assert_eq!(rx.recv(), Ok(1));
+/// ```
+///
+/// ```should_panic
+/// use std::sync::mpsc;
+/// use semtraits::OrFull;
+///
+/// let (tx, _rx) = mpsc::sync_channel(1);
+/// tx.try_send(1).or_full();
+/// tx.try_send(2).or_full(); // panics, channel full
+/// ```
+pub trait OrFull {
+    #[track_caller]
+    fn or_full(self);
+}
+
+/// Panics if an operation with a deadline ran out of time.
+///
+/// This is for timeouts that should never be hit, like a receive from a
+/// worker that always replies quickly. A timed out receive on a closed
+/// channel panics like [`OrClosed`].
+///
+/// ```
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+/// use semtraits::OrTimeout;
+///
+/// let (tx, rx) = mpsc::channel();
+/// tx.send(1).unwrap();
+/// assert_eq!(rx.recv_timeout(Duration::from_secs(1)).or_timeout(), 1);
+/// ```
+///
+/// ```should_panic
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+/// use semtraits::OrTimeout;
+///
+/// let (_tx, rx) = mpsc::channel::<i32>();
+/// rx.recv_timeout(Duration::from_millis(10)).or_timeout(); // panics, operation timed out
+/// ```
+pub trait OrTimeout {
+    type Value;
+
+    #[track_caller]
+    fn or_timeout(self) -> Self::Value;
+}
+
+/// Tries to send a value, dropping it if the channel is full or closed.
+///
+/// This is for lossy channels, like metrics or telemetry, where falling
+/// behind should drop values rather than slow the sender down.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use semtraits::SendOrDrop;
+///
+/// let (tx, rx) = mpsc::sync_channel(1);
+/// assert!(tx.try_send_or_drop(1));
+/// // full, so 2 is dropped.
+/// assert!(!tx.try_send_or_drop(2));
+/// assert_eq!(rx.try_recv(), Ok(1));
+/// ```
+///
+/// Use [`OrFull`] instead to panic when the channel is full.
+pub trait SendOrDrop {
+    type Item;
+
+    /// Returns whether the value was sent.
+    fn try_send_or_drop(&self, value: Self::Item) -> bool;
+}
+
+/// Pushes onto a fixed-size queue, panicking if it is full.
+///
+/// Real-time code, like an audio callback, sizes its queues so that they
+/// never fill up and can't wait for room, so a full queue is a bug.
+///
+/// ```
+/// use semtraits::{Pop, Push};
+///
+/// let (mut producer, mut consumer) = rtrb::RingBuffer::new(2);
+/// producer.push_or_full(1);
+/// producer.push_or_full(2);
+/// assert_eq!(consumer.pop_or_empty(), 1);
+/// ```
+///
+/// ```should_panic
+/// use semtraits::Push;
+///
+/// let (mut producer, _consumer) = rtrb::RingBuffer::new(1);
+/// producer.push_or_full(1);
+/// producer.push_or_full(2); // panics, channel full
+/// ```
+#[cfg(feature = "rtrb")]
+pub trait Push {
+    type Item;
+
+    #[track_caller]
+    fn push_or_full(&mut self, value: Self::Item);
+}
+
+/// Pops from a fixed-size queue, panicking if it is empty.
+///
+/// This is the consuming side of [`Push`], for when the producer is
+/// known to have pushed a value already.
+///
+/// ```should_panic
+/// use semtraits::Pop;
+///
+/// let (_producer, mut consumer) = rtrb::RingBuffer::<i32>::new(1);
+/// consumer.pop_or_empty(); // panics, channel empty
+/// ```
+#[cfg(feature = "rtrb")]
+pub trait Pop {
+    type Item;
+
+    #[track_caller]
+    fn pop_or_empty(&mut self) -> Self::Item;
+}
+
+/// Replaces the value seen by the reading side of a single-writer,
+/// single-reader buffer.
+///
+/// Unlike a queue, values that the reader never saw are overwritten, so
+/// the writer never waits on a slow reader.
+///
+/// ```
+/// use semtraits::{Peek, Publish};
+///
+/// let (mut input, mut output) = triple_buffer::triple_buffer(&0);
+/// input.publish_value(1);
+/// input.publish_value(2);
+/// assert_eq!(*output.latest(), 2);
+/// ```
+#[cfg(feature = "triple-buffer")]
+pub trait Publish {
+    type Item;
+
+    fn publish_value(&mut self, value: Self::Item);
+}
+
+/// Reads the most recent value written to a single-writer,
+/// single-reader buffer.
+///
+/// See [`Publish`] for the writing side. Reading the same value again
+/// is allowed, so this never fails.
+#[cfg(feature = "triple-buffer")]
+pub trait Peek {
+    type Item;
+
+    fn latest(&mut self) -> &Self::Item;
+}
+
+/// Reads a published copy of data that has a single writer and many
+/// readers.
+///
+/// The snapshot stays the same for as long as its guard is held, even
+/// if the writer publishes new changes. Returns `None` once the writer
+/// has been dropped.
+///
+/// ```
+/// use left_right::Absorb;
+/// use semtraits::{Share, Snapshot};
+///
+/// #[derive(Clone, Default)]
+/// struct Counter(i32);
+///
+/// impl Absorb<i32> for Counter {
+///     fn absorb_first(&mut self, add: &mut i32, _: &Self) {
+///         self.0 += *add;
+///     }
+///     fn sync_with(&mut self, first: &Self) {
+///         self.0 = first.0;
+///     }
+/// }
+///
+/// let (mut write, read) = left_right::new::<Counter, i32>();
+/// let reader = read.share();
+/// write.append(2).publish();
+/// assert_eq!(reader.snapshot().unwrap().0, 2);
+///
+/// drop(write);
+/// assert!(reader.snapshot().is_none());
+/// ```
+#[cfg(any(feature = "evmap", feature = "left-right"))]
+pub trait Snapshot {
+    /// What the guard derefs to. This can borrow from the handle, as
+    /// evmap's `MapReadRef` does.
+    type Target<'a>: ?Sized
+    where
+        Self: 'a;
+    type Guard<'a>: core::ops::Deref<Target = Self::Target<'a>>
+    where
+        Self: 'a;
+
+    fn snapshot(&self) -> Option<Self::Guard<'_>>;
+}
+
+/// Routes a value that failed to send on a bounded channel, depending on
+/// whether the channel was full or closed.
+///
+/// A full channel is usually backpressure that the sender can recover
+/// from, like by dropping the value or retrying later, while a closed
+/// channel means nothing will ever receive it.
+///
+/// ```
+/// use crossbeam_channel::bounded;
+/// use semtraits::TrySendOr;
+///
+/// let (tx, rx) = bounded(1);
+/// let mut dropped = Vec::new();
+/// let mut dead_letters = Vec::new();
+/// tx.try_send(1).try_send_or(|v| dropped.push(v), |v| dead_letters.push(v)).unwrap();
+/// // full
+/// let _ = tx.try_send(2).try_send_or(|v| dropped.push(v), |v| dead_letters.push(v));
+///
+/// drop(rx);
+/// // closed
+/// let _ = tx.try_send(3).try_send_or(|v| dropped.push(v), |v| dead_letters.push(v));
+/// assert_eq!(dropped, [2]);
+/// assert_eq!(dead_letters, [3]);
+/// ```
+#[cfg(feature = "crossbeam-channel")]
+pub trait TrySendOr {
+    type Unsent;
+
+    /// Returns `Err(())` if the value was not sent, after passing it to
+    /// `on_full` or `on_closed`.
+    #[allow(
+        clippy::result_unit_err,
+        reason = "the unsent value has been handed to a closure"
+    )]
+    fn try_send_or(
+        self,
+        on_full: impl FnOnce(Self::Unsent),
+        on_closed: impl FnOnce(Self::Unsent),
+    ) -> Result<(), ()>;
+}
+
+/// Falls back to a value when a receive with a timeout doesn't get
+/// anything, whether it timed out or the channel is closed.
+///
+/// `f` is passed `true` if the receive timed out, and `false` if every
+/// sender has been dropped.
+///
+/// ```
+/// use std::time::Duration;
+/// use crossbeam_channel::unbounded;
+/// use semtraits::RecvTimeoutOr;
+///
+/// let (tx, rx) = unbounded::<&str>();
+/// let wait = Duration::from_millis(10);
+/// let status = |timed_out| if timed_out { "idle" } else { "stopped" };
+/// assert_eq!(rx.recv_timeout(wait).recv_timeout_or(status), "idle");
+///
+/// drop(tx);
+/// assert_eq!(rx.recv_timeout(wait).recv_timeout_or(status), "stopped");
+/// ```
+#[cfg(feature = "crossbeam-channel")]
+pub trait RecvTimeoutOr {
     type Value;
 
-    fn or_closed(self) -> Self::Value;
+    fn recv_timeout_or(self, f: impl FnOnce(bool) -> Self::Value) -> Self::Value;
+}
+
+/// Creates a new handle to the same underlying OS resource.
+///
+/// Unlike [`Share`], this is fallible and creates a new file
+/// descriptor/handle. Both handles refer to the same file or socket,
+/// so reading through one will consume data that the other would
+/// have seen.
+///
+/// ```
+/// use std::io::{Read, Write};
+/// use std::net::{TcpListener, TcpStream};
+/// use semtraits::CloneFd;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+///
+/// // accept through the cloned listener.
+/// let (mut server, _) = listener.clone_fd().unwrap().accept().unwrap();
+/// let mut writer = server.clone_fd().unwrap();
+/// writer.write_all(b"hi").unwrap();
+/// drop(writer);
+/// drop(server);
+///
+/// let mut buf = String::new();
+/// client.read_to_string(&mut buf).unwrap();
+/// assert_eq!(buf, "hi");
+/// ```
+#[cfg(feature = "std")]
+pub trait CloneFd: Sized {
+    fn clone_fd(&self) -> std::io::Result<Self>;
+}
+
+/// Gets separate handles for reading and writing a stream.
+///
+/// Both handles are new [`clone_fd`](CloneFd::clone_fd)s of the same OS
+/// socket, so this is only a convenience for moving reads and writes to
+/// different threads. Shutting down either handle shuts down the socket
+/// for all of them.
+///
+/// ```
+/// use std::io::{Read, Write};
+/// use std::net::{Shutdown, TcpListener, TcpStream};
+/// use std::thread;
+/// use semtraits::SplitClone;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+/// let (mut server, _) = listener.accept().unwrap();
+///
+/// let (mut read, mut write) = client.split_clone().unwrap();
+/// thread::spawn(move || {
+///     write.write_all(b"hi").unwrap();
+///     write.shutdown(Shutdown::Write).unwrap();
+/// });
+///
+/// let mut buf = String::new();
+/// server.read_to_string(&mut buf).unwrap();
+/// assert_eq!(buf, "hi");
+/// server.write_all(b"bye").unwrap();
+/// drop(server);
+///
+/// buf.clear();
+/// read.read_to_string(&mut buf).unwrap();
+/// assert_eq!(buf, "bye");
+/// ```
+#[cfg(feature = "std")]
+pub trait SplitClone: CloneFd {
+    /// Returns a `(read, write)` pair of handles.
+    fn split_clone(&self) -> std::io::Result<(Self, Self)> {
+        Ok((self.clone_fd()?, self.clone_fd()?))
+    }
 }
 
 /// Gets the value out of a lock, panic if the lock has been poisoned.
@@ -194,8 +829,1211 @@ pub trait OrClosed {
 ///
 /// assert_eq!(*mutex.lock().or_poisoned(), 12);
 /// ```
+///
+/// Locks that can't be poisoned, like tokio's, pass their guards through
+/// unchanged. This lets code written against std keep its
+/// `or_poisoned` calls when switching lock types.
 pub trait OrPoisoned {
     type Value;
 
+    #[track_caller]
     fn or_poisoned(self) -> Self::Value;
 }
+
+/// Gets the result of a joined thread or task, continuing its panic if
+/// it panicked.
+///
+/// The original panic payload is resumed on the joining thread, so the
+/// parent fails with the same message as the child.
+///
+/// ```should_panic
+/// use std::thread;
+/// use semtraits::OrPanicked;
+///
+/// let worker = thread::spawn(|| panic!("worker failed"));
+/// worker.join().or_panicked(); // panics, worker failed
+/// ```
+pub trait OrPanicked {
+    type Value;
+
+    #[track_caller]
+    fn or_panicked(self) -> Self::Value;
+}
+
+/// Gets the value out of a [`RefCell`] borrow, panic if the cell is
+/// already borrowed.
+///
+/// This is implemented on the output of [`RefCell::try_borrow`] and
+/// [`RefCell::try_borrow_mut`], with the same panic messages as
+/// [`RefCell::borrow`] and [`RefCell::borrow_mut`].
+///
+/// ```
+/// use std::cell::RefCell;
+/// use semtraits::OrBorrowed;
+///
+/// let cell = RefCell::new(1);
+/// *cell.try_borrow_mut().or_borrowed() += 1;
+/// assert_eq!(*cell.try_borrow().or_borrowed(), 2);
+/// ```
+///
+/// ```should_panic
+/// use std::cell::RefCell;
+/// use semtraits::OrBorrowed;
+///
+/// let cell = RefCell::new(1);
+/// let _guard = cell.borrow_mut();
+/// cell.try_borrow().or_borrowed(); // panics, already mutably borrowed
+/// ```
+///
+/// ```should_panic
+/// use std::cell::RefCell;
+/// use semtraits::OrBorrowed;
+///
+/// let cell = RefCell::new(1);
+/// let _guard = cell.borrow();
+/// cell.try_borrow_mut().or_borrowed(); // panics, already borrowed
+/// ```
+///
+/// [`RefCell`]: core::cell::RefCell
+/// [`RefCell::try_borrow`]: core::cell::RefCell::try_borrow
+/// [`RefCell::try_borrow_mut`]: core::cell::RefCell::try_borrow_mut
+/// [`RefCell::borrow`]: core::cell::RefCell::borrow
+/// [`RefCell::borrow_mut`]: core::cell::RefCell::borrow_mut
+pub trait OrBorrowed {
+    type Value;
+
+    fn or_borrowed(self) -> Self::Value;
+}
+
+/// Gets a value from a keyed container, panicking if the key is missing.
+///
+/// This is for lookups where the key is known to be present, like a
+/// handle to an entity that hasn't been removed yet.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use semtraits::GetOr;
+///
+/// let mut ages = HashMap::new();
+/// ages.insert("alice", 30);
+/// assert_eq!(*ages.get_or_missing("alice"), 30);
+/// ```
+///
+/// ```should_panic
+/// use std::collections::BTreeMap;
+/// use semtraits::GetOr;
+///
+/// let names: BTreeMap<u32, &str> = BTreeMap::new();
+/// names.get_or_missing(&1); // panics, key not present
+/// ```
+pub trait GetOr<K: ?Sized> {
+    type Value;
+
+    #[track_caller]
+    fn get_or_missing(&self, key: &K) -> &Self::Value;
+}
+
+/// Initializes a cell with a fallible closure, leaving it empty if the
+/// closure fails.
+///
+/// This is for lazily loaded values that can fail to load, like reading
+/// a config file, where a later call should get to try again.
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use semtraits::GetOrTryInit;
+///
+/// let port: OnceLock<u16> = OnceLock::new();
+/// assert!(port.or_try_init(|| "not a port".parse()).is_err());
+/// assert_eq!(port.get(), None);
+///
+/// assert_eq!(port.or_try_init(|| "8080".parse()), Ok(&8080));
+/// // already initialized, so the closure isn't called.
+/// assert_eq!(port.or_try_init(|| "80".parse()), Ok(&8080));
+/// ```
+pub trait GetOrTryInit {
+    type Value;
+
+    /// Gets the value, calling `f` to initialize the cell if it is
+    /// empty.
+    ///
+    /// If several threads initialize the cell at once, each may call
+    /// `f`, and the first value to be stored is kept.
+    fn or_try_init<E>(&self, f: impl FnOnce() -> Result<Self::Value, E>)
+    -> Result<&Self::Value, E>;
+}
+
+/// Checks whether a reference counted pointer is the only one left.
+///
+/// A pointer is unique when there are no other strong or weak pointers
+/// to the same value, so nothing else can observe it being modified or
+/// dropped.
+///
+/// ```
+/// use std::sync::Arc;
+/// use semtraits::{Share, SharedCount};
+///
+/// let data = Arc::new(1);
+/// assert!(data.is_unique());
+///
+/// let other = data.share();
+/// assert!(!data.is_unique());
+/// drop(other);
+/// assert!(data.is_unique());
+/// ```
+pub trait SharedCount {
+    fn is_unique(&self) -> bool;
+}
+
+/// A shared counter that can be bumped from many places at once.
+///
+/// All operations use [`Relaxed`] ordering, so the counter should not
+/// be used to synchronize other memory accesses.
+///
+/// ```
+/// use std::sync::{Arc, atomic::AtomicUsize};
+/// use std::thread;
+/// use semtraits::{Counter, Share};
+///
+/// let counter = Arc::new(AtomicUsize::new(0));
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let counter = counter.share();
+///         thread::spawn(move || counter.bump())
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(counter.current(), 4);
+/// ```
+///
+/// [`try_bump`](Counter::try_bump) refuses to wrap around, for
+/// long-running counters where overflow would be a bug.
+///
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use semtraits::Counter;
+///
+/// let counter = AtomicUsize::new(usize::MAX - 1);
+/// assert_eq!(counter.try_bump(), Some(usize::MAX - 1));
+/// assert_eq!(counter.try_bump(), None);
+/// assert_eq!(counter.current(), usize::MAX);
+/// ```
+///
+/// [`Relaxed`]: core::sync::atomic::Ordering::Relaxed
+pub trait Counter {
+    /// Increments the counter, returning the previous value.
+    ///
+    /// This wraps around on overflow.
+    fn bump(&self) -> usize;
+
+    /// Increments the counter if it will not overflow, returning the
+    /// previous value.
+    ///
+    /// Returns [`None`] and leaves the counter untouched if it is at
+    /// its maximum value.
+    fn try_bump(&self) -> Option<usize>;
+
+    /// Gets the current value of the counter.
+    fn current(&self) -> usize;
+}
+
+/// Reads and writes a shared atomic without picking an ordering.
+///
+/// This is a uniform API over the `Atomic*` types for plain values, like
+/// flags and settings held behind an [`Arc`]. Like [`Counter`], every
+/// operation uses [`Relaxed`] ordering; use the atomic's own methods
+/// when it guards other data.
+///
+/// ```
+/// use std::sync::atomic::{AtomicBool, AtomicU32};
+/// use semtraits::SharedAtomic;
+///
+/// let level = AtomicU32::new(1);
+/// level.set(3);
+/// assert_eq!(level.get(), 3);
+///
+/// let dirty = AtomicBool::new(false);
+/// dirty.set(true);
+/// // take the flag, clearing it.
+/// assert!(dirty.swap_in(false));
+/// assert!(!dirty.get());
+/// ```
+///
+/// [`Arc`]: std::sync::Arc
+/// [`Relaxed`]: core::sync::atomic::Ordering::Relaxed
+pub trait SharedAtomic {
+    type Value;
+
+    /// Loads the value.
+    fn get(&self) -> Self::Value;
+
+    /// Stores a new value.
+    fn set(&self, value: Self::Value);
+
+    /// Stores a new value, returning the previous one.
+    fn swap_in(&self, value: Self::Value) -> Self::Value;
+}
+
+/// Gets the value for the current thread, initializing it if this
+/// thread hasn't accessed it yet.
+///
+/// Per-thread storage is usually shared between threads with an
+/// [`Arc`], where each thread sees its own value.
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::sync::{Arc, Barrier};
+/// use std::thread;
+/// use semtraits::{PerThread, Share};
+/// use thread_local::ThreadLocal;
+///
+/// let local = Arc::new(ThreadLocal::new());
+/// let barrier = Arc::new(Barrier::new(3));
+/// let handles: Vec<_> = (1..=3)
+///     .map(|i| {
+///         let local = local.share();
+///         let barrier = barrier.share();
+///         thread::spawn(move || {
+///             let cell = local.per_thread(|| Cell::new(0));
+///             cell.set(cell.get() + i);
+///             assert_eq!(local.per_thread(|| unreachable!()).get(), i);
+///             barrier.wait();
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// let mut local = Arc::into_inner(local).unwrap();
+/// let mut values: Vec<_> = local.iter_mut().map(|cell| cell.get()).collect();
+/// values.sort();
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+///
+/// [`Arc`]: std::sync::Arc
+#[cfg(feature = "thread-local")]
+pub trait PerThread {
+    type Value;
+
+    fn per_thread(&self, f: impl FnOnce() -> Self::Value) -> &Self::Value;
+}
+
+/// Gets the number of receivers that are still listening.
+///
+/// Producers can use this to stop doing work when nobody is around
+/// to see the result.
+///
+/// ```
+/// use tokio::sync::watch;
+/// use semtraits::{ReceiverCount, Share};
+///
+/// let (tx, rx) = watch::channel(0);
+/// assert_eq!(tx.receiver_count(), 1);
+///
+/// let rx1 = rx.share();
+/// let rx2 = tx.subscribe();
+/// assert_eq!(tx.receiver_count(), 3);
+///
+/// drop(rx);
+/// drop(rx1);
+/// assert_eq!(tx.receiver_count(), 1);
+/// drop(rx2);
+/// assert_eq!(tx.receiver_count(), 0);
+/// ```
+#[cfg(feature = "tokio")]
+pub trait ReceiverCount {
+    fn receiver_count(&self) -> usize;
+}
+
+/// Reports how full a bounded tokio [`mpsc`] channel is, for monitoring
+/// backpressure.
+///
+/// Both ends see the same channel, so a producer can check how far
+/// behind its consumer is.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::ChannelMetrics;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = mpsc::channel(4);
+/// tx.send(1).await.unwrap();
+/// assert_eq!((tx.queued(), tx.free_slots()), (1, 3));
+/// assert_eq!((rx.queued(), rx.free_slots()), (1, 3));
+///
+/// rx.recv().await.unwrap();
+/// assert!(tx.is_drained());
+/// # }
+/// ```
+///
+/// [`mpsc`]: tokio::sync::mpsc
+#[cfg(feature = "tokio")]
+pub trait ChannelMetrics {
+    /// The number of slots in use, by values waiting to be received or
+    /// by [permits](tokio::sync::mpsc::Permit) that haven't been sent
+    /// to yet.
+    fn queued(&self) -> usize;
+
+    /// The number of values that can be sent before the channel is full.
+    fn free_slots(&self) -> usize;
+
+    fn is_drained(&self) -> bool {
+        self.queued() == 0
+    }
+}
+
+/// Broadcasts a value on a best effort basis, dropping it if there is
+/// no one subscribed.
+///
+/// A broadcast channel with no subscribers isn't closed for good, since
+/// new receivers can still subscribe later, so this doesn't panic like
+/// [`OrClosed`].
+///
+/// ```
+/// use tokio::sync::broadcast;
+/// use semtraits::BroadcastSend;
+///
+/// let (tx, rx) = broadcast::channel(4);
+/// assert_eq!(tx.send_to_any("hello"), Some(1));
+///
+/// drop(rx);
+/// assert_eq!(tx.send_to_any("anyone?"), None);
+/// ```
+#[cfg(feature = "tokio")]
+pub trait BroadcastSend {
+    type Item;
+
+    /// Returns the number of receivers the value was sent to, or `None`
+    /// if there were none.
+    fn send_to_any(&self, value: Self::Item) -> Option<usize>;
+}
+
+/// Updates a tokio [`watch`] channel only if the value is different.
+///
+/// Receivers aren't told about a change when the new value is equal to
+/// the current one, so they don't wake up to redo work for nothing.
+///
+/// ```
+/// use tokio::sync::watch;
+/// use semtraits::SendIfChanged;
+///
+/// let (tx, rx) = watch::channel(1);
+/// assert!(!tx.send_if_changed(1));
+/// assert!(!rx.has_changed().unwrap());
+///
+/// assert!(tx.send_if_changed(2));
+/// assert!(rx.has_changed().unwrap());
+/// ```
+///
+/// [`watch`]: tokio::sync::watch
+#[cfg(feature = "tokio")]
+pub trait SendIfChanged {
+    type Item;
+
+    /// Returns whether the value changed. The value is updated even if
+    /// there are no receivers.
+    fn send_if_changed(&self, value: Self::Item) -> bool;
+}
+
+/// Waits for every task in a tokio [`JoinSet`], panicking if any of
+/// them panicked.
+///
+/// This is for a group of tasks that must all succeed, where a panic in
+/// one of them should not go unnoticed. Cancelled tasks are ignored.
+///
+/// ```should_panic
+/// use tokio::task::JoinSet;
+/// use semtraits::JoinAll;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut set = JoinSet::new();
+/// set.spawn(async {});
+/// set.spawn(async { panic!("worker failed") });
+/// set.await_all_or_panic().await; // panics, task panicked
+/// # }
+/// ```
+///
+/// [`JoinSet`]: tokio::task::JoinSet
+#[cfg(feature = "tokio")]
+pub trait JoinAll {
+    /// Completes once the set is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics as soon as a panicked task is joined. The tasks that are
+    /// still running are aborted when the set is dropped.
+    fn await_all_or_panic(&mut self) -> impl Future<Output = ()> + Send;
+}
+
+/// Tries to lock a tokio [`RwLock`] without waiting, panic if the lock
+/// is contended.
+///
+/// Async locks can't be poisoned, but they can be held by another task
+/// when the lock is only tried. This is for code where the lock is
+/// expected to be free, like when there is only one task using it.
+///
+/// ```
+/// use tokio::sync::RwLock;
+/// use semtraits::TryReadWrite;
+///
+/// let lock = RwLock::new(1);
+/// *lock.try_write_or_contended() += 1;
+/// assert_eq!(*lock.try_read_or_contended(), 2);
+/// ```
+///
+/// ```should_panic
+/// use tokio::sync::RwLock;
+/// use semtraits::TryReadWrite;
+///
+/// let lock = RwLock::new(1);
+/// let _guard = lock.try_write_or_contended();
+/// let _ = lock.try_read_or_contended(); // panics, lock contended
+/// ```
+///
+/// [`RwLock`]: tokio::sync::RwLock
+#[cfg(feature = "tokio")]
+pub trait TryReadWrite {
+    type Target: ?Sized;
+
+    fn try_read_or_contended(&self) -> tokio::sync::RwLockReadGuard<'_, Self::Target>;
+    fn try_write_or_contended(&self) -> tokio::sync::RwLockWriteGuard<'_, Self::Target>;
+}
+
+/// Locks a tokio [`Mutex`] from synchronous code.
+///
+/// This is for sync code that shares state with async tasks, like a
+/// [`spawn_blocking`] closure.
+///
+/// ```
+/// use std::sync::Arc;
+/// use tokio::sync::Mutex;
+/// use semtraits::{BlockingLock, OrPoisoned, Share};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let state = Arc::new(Mutex::new(0));
+/// let shared = state.share();
+/// tokio::task::spawn_blocking(move || {
+///     *shared.lock_blocking().or_poisoned() += 1;
+/// })
+/// .await
+/// .unwrap();
+/// assert_eq!(*state.lock().await, 1);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called from within an async context.
+///
+/// [`Mutex`]: tokio::sync::Mutex
+/// [`spawn_blocking`]: tokio::task::spawn_blocking
+#[cfg(feature = "tokio")]
+pub trait BlockingLock {
+    type Target: ?Sized;
+
+    #[track_caller]
+    fn lock_blocking(&self) -> tokio::sync::MutexGuard<'_, Self::Target>;
+}
+
+/// Locks a shared tokio [`Mutex`], getting a guard that keeps the
+/// [`Arc`] alive.
+///
+/// Owned guards are `'static`, so a locked guard can be moved into a
+/// spawned task. tokio locks can't be poisoned, so
+/// [`or_poisoned`](OrPoisoned::or_poisoned) on the guard returns it
+/// as is, for code that is written against std locks.
+///
+/// ```
+/// use std::sync::Arc;
+/// use tokio::sync::Mutex;
+/// use semtraits::{LockOwned, OrPoisoned, Share};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let count = Arc::new(Mutex::new(0));
+/// let mut guard = LockOwned::lock_owned(count.share()).await.or_poisoned();
+/// tokio::spawn(async move { *guard += 1 }).await.unwrap();
+/// assert_eq!(*count.lock().await, 1);
+/// # }
+/// ```
+///
+/// The inherent method with the same name is used when calling on an
+/// `Arc` directly.
+///
+/// [`Mutex`]: tokio::sync::Mutex
+/// [`Arc`]: std::sync::Arc
+#[cfg(feature = "tokio")]
+pub trait LockOwned {
+    type Target: ?Sized;
+
+    fn lock_owned(self) -> impl Future<Output = tokio::sync::OwnedMutexGuard<Self::Target>> + Send;
+}
+
+/// Locks a shared tokio [`RwLock`], getting a guard that keeps the
+/// [`Arc`] alive.
+///
+/// Owned guards are `'static`, so they can be held across await points
+/// in a spawned task.
+///
+/// ```
+/// use std::sync::Arc;
+/// use tokio::sync::RwLock;
+/// use semtraits::{ReadWriteOwned, Share};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let config = Arc::new(RwLock::new(1));
+/// let mut guard = ReadWriteOwned::write_owned(config.share()).await;
+/// tokio::spawn(async move { *guard += 1 }).await.unwrap();
+/// assert_eq!(*ReadWriteOwned::read_owned(config).await, 2);
+/// # }
+/// ```
+///
+/// The inherent methods with the same names are used when calling on an
+/// `Arc` directly.
+///
+/// [`RwLock`]: tokio::sync::RwLock
+/// [`Arc`]: std::sync::Arc
+#[cfg(feature = "tokio")]
+pub trait ReadWriteOwned {
+    type Target: ?Sized;
+
+    fn read_owned(
+        self,
+    ) -> impl Future<Output = tokio::sync::OwnedRwLockReadGuard<Self::Target>> + Send;
+    fn write_owned(
+        self,
+    ) -> impl Future<Output = tokio::sync::OwnedRwLockWriteGuard<Self::Target>> + Send;
+}
+
+/// Locks a shared parking_lot [`Mutex`], getting a guard that keeps the
+/// [`Arc`] alive.
+///
+/// The guard borrows nothing, so it can be stored without a lifetime or
+/// moved into a `'static` task. It still has to be dropped on the thread
+/// that locked it.
+///
+/// ```
+/// use std::sync::Arc;
+/// use parking_lot::{ArcMutexGuard, Mutex, RawMutex};
+/// use semtraits::LockArc;
+///
+/// struct Session {
+///     log: ArcMutexGuard<RawMutex, Vec<String>>,
+/// }
+///
+/// fn start<L: LockArc<Target = Vec<String>>>(log: &L) -> Session {
+///     Session { log: log.lock_arc() }
+/// }
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let mut session = start(&log);
+/// session.log.push(String::from("started"));
+/// drop(session);
+/// assert_eq!(*log.lock(), ["started"]);
+/// ```
+///
+/// The inherent [`lock_arc`] is used when calling on an `Arc` directly.
+///
+/// [`Mutex`]: parking_lot::Mutex
+/// [`Arc`]: std::sync::Arc
+/// [`lock_arc`]: parking_lot::lock_api::Mutex::lock_arc
+#[cfg(feature = "parking_lot")]
+pub trait LockArc {
+    type Target: ?Sized;
+
+    fn lock_arc(&self) -> parking_lot::ArcMutexGuard<parking_lot::RawMutex, Self::Target>;
+}
+
+/// Waits for the next tick of a tokio [`Interval`].
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio::time::{self, Instant};
+/// use semtraits::Tick;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let start = Instant::now();
+/// let mut interval = time::interval(Duration::from_secs(1));
+/// // the first tick completes immediately.
+/// assert_eq!(interval.tick_now().await, start);
+/// assert_eq!(interval.tick_now().await, start + Duration::from_secs(1));
+///
+/// time::advance(Duration::from_millis(500)).await;
+/// interval.reset_interval();
+/// let reset_at = Instant::now();
+/// assert_eq!(interval.tick_now().await, reset_at + Duration::from_secs(1));
+/// # }
+/// ```
+///
+/// [`Interval`]: tokio::time::Interval
+#[cfg(feature = "tokio")]
+pub trait Tick {
+    /// Completes when the next tick is reached, returning the instant
+    /// that the tick was scheduled for.
+    fn tick_now(&mut self) -> impl Future<Output = tokio::time::Instant> + Send;
+
+    /// Restarts the interval, so that the next tick is one period from
+    /// now.
+    fn reset_interval(&mut self);
+}
+
+/// Receives from a tokio [`broadcast`] channel, skipping over any values
+/// that were missed by lagging behind.
+///
+/// This is for receivers that only care about the latest values: a
+/// [`Lagged`] error is swallowed and receiving continues from the oldest
+/// value still in the channel. [`Closed`] is still returned as an error.
+///
+/// ```
+/// use tokio::sync::broadcast::{self, error::RecvError};
+/// use semtraits::RecvSkipLagged;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = broadcast::channel(2);
+/// for i in 0..5 {
+///     tx.send(i).unwrap();
+/// }
+/// // 0, 1 and 2 were pushed out of the channel.
+/// assert_eq!(rx.recv_skip_lagged().await, Ok(3));
+/// assert_eq!(rx.recv_skip_lagged().await, Ok(4));
+///
+/// drop(tx);
+/// assert_eq!(rx.recv_skip_lagged().await, Err(RecvError::Closed));
+/// # }
+/// ```
+///
+/// [`broadcast`]: tokio::sync::broadcast
+/// [`Lagged`]: tokio::sync::broadcast::error::RecvError::Lagged
+/// [`Closed`]: tokio::sync::broadcast::error::RecvError::Closed
+#[cfg(feature = "tokio")]
+pub trait RecvSkipLagged {
+    type Item;
+
+    /// Waits for the next value that has not been skipped.
+    ///
+    /// The only error that can be returned is
+    /// [`RecvError::Closed`](tokio::sync::broadcast::error::RecvError::Closed).
+    fn recv_skip_lagged(
+        &mut self,
+    ) -> impl Future<Output = Result<Self::Item, tokio::sync::broadcast::error::RecvError>> + Send;
+}
+
+/// Receives only the newest value from a tokio [`broadcast`] channel,
+/// dropping everything that was sent before it.
+///
+/// This is for consumers that only show the current state, like a UI,
+/// where working through a backlog of older values would be wasted.
+/// If nothing has been sent yet, this waits for the next value.
+///
+/// ```
+/// use tokio::sync::broadcast::{self, error::RecvError};
+/// use semtraits::RecvLatest;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = broadcast::channel(8);
+/// for i in 0..5 {
+///     tx.send(i).unwrap();
+/// }
+/// assert_eq!(rx.recv_latest().await, Ok(4));
+///
+/// drop(tx);
+/// assert_eq!(rx.recv_latest().await, Err(RecvError::Closed));
+/// # }
+/// ```
+///
+/// [`broadcast`]: tokio::sync::broadcast
+#[cfg(feature = "tokio")]
+pub trait RecvLatest {
+    type Item;
+
+    /// Waits for a value, then returns the last one in the channel.
+    ///
+    /// Like [`RecvSkipLagged`], the only error that can be returned is
+    /// [`RecvError::Closed`](tokio::sync::broadcast::error::RecvError::Closed),
+    /// once every value has been received.
+    fn recv_latest(
+        &mut self,
+    ) -> impl Future<Output = Result<Self::Item, tokio::sync::broadcast::error::RecvError>> + Send;
+}
+
+/// Runs a future to completion from synchronous code.
+///
+/// This is [`Runtime::block_on`] and [`Handle::block_on`] under one
+/// name, for code bridging sync and async that shouldn't care which
+/// of the two it was given.
+///
+/// ```
+/// use tokio::runtime::Builder;
+/// use semtraits::BlockOn;
+///
+/// let runtime = Builder::new_current_thread().build().unwrap();
+/// assert_eq!(runtime.block_on_future(async { 1 + 1 }), 2);
+/// ```
+///
+/// [`Runtime::block_on`]: tokio::runtime::Runtime::block_on
+/// [`Handle::block_on`]: tokio::runtime::Handle::block_on
+#[cfg(feature = "tokio")]
+pub trait BlockOn {
+    /// Blocks the current thread until `future` completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an async context.
+    fn block_on_future<F: Future>(&self, future: F) -> F::Output;
+}
+
+/// Receives a batch of values from a tokio [`mpsc`] channel, panicking if
+/// the channel is closed.
+///
+/// `recv_many` reports a closed (and drained) channel by receiving no
+/// values, which is easy to mistake for an empty batch.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::RecvMany;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = mpsc::channel(8);
+/// for i in 0..5 {
+///     tx.send(i).await.unwrap();
+/// }
+/// let mut buf = Vec::new();
+/// assert_eq!(rx.recv_many_or_closed(&mut buf, 3).await, 3);
+/// assert_eq!(rx.recv_many_or_closed(&mut buf, 3).await, 2);
+/// assert_eq!(buf, [0, 1, 2, 3, 4]);
+/// # }
+/// ```
+///
+/// [`mpsc`]: tokio::sync::mpsc
+#[cfg(feature = "tokio")]
+pub trait RecvMany {
+    type Item;
+
+    /// Waits for up to `limit` values, appending them to `buf` and
+    /// returning how many were received.
+    ///
+    /// A `limit` of zero returns zero immediately without panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel is closed and there are no values left to
+    /// receive.
+    fn recv_many_or_closed(
+        &mut self,
+        buf: &mut std::vec::Vec<Self::Item>,
+        limit: usize,
+    ) -> impl Future<Output = usize> + Send;
+}
+
+/// Receives everything left in a tokio [`mpsc`] channel.
+///
+/// This is for shutting down a consumer that must still process every
+/// message that was sent, like flushing queued writes.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::Drain;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = mpsc::channel(8);
+/// tokio::spawn(async move {
+///     for i in 0..3 {
+///         tx.send(i).await.unwrap();
+///     }
+/// });
+/// assert_eq!(rx.drain().await, [0, 1, 2]);
+/// # }
+/// ```
+///
+/// [`mpsc`]: tokio::sync::mpsc
+#[cfg(feature = "tokio")]
+pub trait Drain {
+    type Item;
+
+    /// Waits until every sender has been dropped, returning the values
+    /// received in order.
+    fn drain(&mut self) -> impl Future<Output = std::vec::Vec<Self::Item>> + Send;
+}
+
+/// Reserves capacity on a tokio [`mpsc`] channel, panicking if the
+/// channel is closed.
+///
+/// The returned permit guarantees that its send will succeed, so the
+/// value only needs to be created once there is room for it.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::Reserve;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = mpsc::channel(1);
+/// tx.reserve_or_closed().await.send(1);
+/// assert_eq!(rx.recv().await, Some(1));
+/// # }
+/// ```
+///
+/// ```should_panic
+/// use tokio::sync::mpsc;
+/// use semtraits::Reserve;
+///
+/// let (tx, _rx) = mpsc::channel(1);
+/// tx.try_reserve_or_full().send(1);
+/// tx.try_reserve_or_full(); // panics, channel full
+/// ```
+///
+/// [`mpsc`]: tokio::sync::mpsc
+#[cfg(feature = "tokio")]
+pub trait Reserve {
+    type Permit<'a>
+    where
+        Self: 'a;
+
+    /// Waits for a free slot in the channel.
+    fn reserve_or_closed(&self) -> impl Future<Output = Self::Permit<'_>> + Send;
+
+    /// Reserves a free slot without waiting, panicking if the channel is
+    /// full.
+    #[track_caller]
+    fn try_reserve_or_full(&self) -> Self::Permit<'_>;
+}
+
+/// Splits a stream into halves that can be moved to separate tasks.
+///
+/// Both halves refer to the same socket: shutting down the write half
+/// affects the peer's reads, and the socket is only closed once both
+/// halves have been dropped.
+///
+/// ```
+/// use tokio::io::{AsyncReadExt, AsyncWriteExt};
+/// use tokio::net::{TcpListener, TcpStream};
+/// use semtraits::Split;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+/// let client = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+/// let (server, _) = listener.accept().await.unwrap();
+///
+/// let (_read, mut write) = server.split_owned();
+/// tokio::spawn(async move { write.write_all(b"hi").await.unwrap() });
+///
+/// let (mut client_read, _client_write) = client.split_owned();
+/// let mut buf = [0; 2];
+/// client_read.read_exact(&mut buf).await.unwrap();
+/// assert_eq!(&buf, b"hi");
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub trait Split {
+    type ReadHalf;
+    type WriteHalf;
+
+    fn split_owned(self) -> (Self::ReadHalf, Self::WriteHalf);
+}
+
+/// Converts a channel receiver into a [`Stream`] of its values.
+///
+/// The stream ends once the channel is closed and every value sent
+/// before that has been received.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use tokio_stream::StreamExt;
+/// use semtraits::IntoStream;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, rx) = mpsc::channel(4);
+/// tokio::spawn(async move {
+///     for i in 0..3 {
+///         tx.send(i).await.unwrap();
+///     }
+/// });
+/// let values: Vec<i32> = rx.into_stream().collect().await;
+/// assert_eq!(values, [0, 1, 2]);
+/// # }
+/// ```
+///
+/// [`Stream`]: tokio_stream::Stream
+#[cfg(feature = "tokio-stream")]
+pub trait IntoStream {
+    type Stream: tokio_stream::Stream;
+
+    fn into_stream(self) -> Self::Stream;
+}
+
+/// Drops the lagged errors from a broadcast [`Stream`], so that a slow
+/// consumer just misses the values it fell behind on.
+///
+/// ```
+/// use tokio::sync::broadcast;
+/// use tokio_stream::StreamExt;
+/// use semtraits::{IntoStream, SkipLagged};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, rx) = broadcast::channel(2);
+/// let stream = rx.into_stream().skip_lagged();
+/// for i in 0..4 {
+///     tx.send(i).unwrap();
+/// }
+/// drop(tx);
+/// // only the last 2 values fit in the channel.
+/// assert_eq!(stream.collect::<Vec<_>>().await, [2, 3]);
+/// # }
+/// ```
+///
+/// [`Stream`]: tokio_stream::Stream
+#[cfg(feature = "tokio-stream")]
+pub trait SkipLagged {
+    type Item;
+
+    fn skip_lagged(self) -> impl tokio_stream::Stream<Item = Self::Item> + Send;
+}
+
+/// Converts a channel sender into a [`Sink`] of its values.
+///
+/// Closing the sink drops the sender, and sending into a sink whose
+/// receiver is gone returns an error.
+///
+/// ```
+/// use futures::SinkExt;
+/// use tokio::sync::mpsc;
+/// use semtraits::IntoSink;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, mut rx) = mpsc::channel(4);
+/// let mut sink = tx.into_sink();
+/// sink.send(1).await.unwrap();
+/// // dropping the sender inside, so the channel closes.
+/// sink.close();
+/// assert_eq!(rx.recv().await, Some(1));
+/// assert_eq!(rx.recv().await, None);
+/// # }
+/// ```
+///
+/// [`Sink`]: https://docs.rs/futures-sink/latest/futures_sink/trait.Sink.html
+#[cfg(feature = "tokio-util")]
+pub trait IntoSink {
+    type Item;
+
+    fn into_sink(self) -> tokio_util::sync::PollSender<Self::Item>;
+}
+
+/// Turns a stream into one that can be consumed from many places.
+///
+/// Every [`SharedStream`](shared::SharedStream) handle yields every item
+/// from the point it was created, so items are cloned for each handle.
+///
+/// ```
+/// use futures::{StreamExt, executor::block_on, stream};
+/// use semtraits::{Share, ShareStream};
+///
+/// let first = stream::iter([1, 2, 3]).share_stream();
+/// let second = first.share();
+/// assert_eq!(block_on(first.collect::<Vec<_>>()), [1, 2, 3]);
+/// assert_eq!(block_on(second.collect::<Vec<_>>()), [1, 2, 3]);
+/// ```
+#[cfg(feature = "futures")]
+pub trait ShareStream: futures::Stream + Sized {
+    fn share_stream(self) -> shared::SharedStream<Self>
+    where
+        Self::Item: Clone;
+}
+
+/// Lets the output of a future be awaited from many places.
+///
+/// This is [`FutureExt::shared`], named so that it doesn't clash with
+/// [`Share::share`] on futures that are already [`Share`]. Clones of
+/// the returned [`Shared`] future all resolve to a clone of the same
+/// output, and the future only runs once.
+///
+/// ```
+/// use futures::executor::block_on;
+/// use semtraits::{Share, ShareFuture};
+///
+/// let answer = async { 6 * 7 }.share_future();
+/// let other = answer.share();
+/// assert_eq!(block_on(answer), 42);
+/// assert_eq!(block_on(other), 42);
+/// ```
+///
+/// [`FutureExt::shared`]: futures::FutureExt::shared
+/// [`Shared`]: futures::future::Shared
+#[cfg(feature = "futures")]
+pub trait ShareFuture: Future + Sized {
+    fn share_future(self) -> futures::future::Shared<Self>
+    where
+        Self::Output: Clone;
+}
+
+/// Replaces a layer or filter that was made reloadable.
+///
+/// [`reload::Handle`] returns an error if the subscriber has since been
+/// dropped, which is usually a bug in where the handle is kept. This
+/// panics instead. The method has a different name to the handle's own
+/// `reload` so that it isn't shadowed by it.
+///
+/// ```
+/// use tracing_subscriber::{filter::LevelFilter, prelude::*, reload};
+/// use semtraits::{Reload, Share};
+///
+/// let (filter, handle) = reload::Layer::new(LevelFilter::WARN);
+/// let _guard = tracing_subscriber::registry().with(filter).set_default();
+///
+/// // e.g. from a signal handler or admin endpoint.
+/// let admin = handle.share();
+/// admin.reload_layer(LevelFilter::DEBUG);
+/// assert_eq!(handle.clone_current(), Some(LevelFilter::DEBUG));
+/// ```
+///
+/// [`reload::Handle`]: tracing_subscriber::reload::Handle
+#[cfg(feature = "tracing-subscriber")]
+pub trait Reload {
+    type Layer;
+
+    /// # Panics
+    ///
+    /// Panics if the subscriber has been dropped or its lock is poisoned.
+    #[track_caller]
+    fn reload_layer(&self, new: Self::Layer);
+}
+
+/// Waits until nothing is left to receive what a sender sends.
+///
+/// This is for tasks that should stop producing once there is no one
+/// left to consume, like a background refresher whose subscribers have
+/// all gone away.
+///
+/// ```
+/// use tokio::sync::mpsc;
+/// use semtraits::AwaitClosed;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (tx, rx) = mpsc::channel::<i32>(1);
+/// tokio::spawn(async move { drop(rx) });
+/// tx.await_closed().await;
+/// assert!(tx.is_closed());
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub trait AwaitClosed {
+    /// Completes once every receiver has been dropped.
+    fn await_closed(&self) -> impl Future<Output = ()> + Send;
+}
+
+/// Sets a tokio [`OnceCell`], panicking if it already has a value.
+///
+/// This is for cells that are only set once, like a value filled in
+/// during startup, where setting it again is a bug.
+///
+/// ```
+/// use tokio::sync::OnceCell;
+/// use semtraits::OrAlreadySet;
+///
+/// let cell = OnceCell::new();
+/// cell.set(1).or_already_set();
+/// assert_eq!(cell.get(), Some(&1));
+/// ```
+///
+/// ```should_panic
+/// use tokio::sync::OnceCell;
+/// use semtraits::OrAlreadySet;
+///
+/// let cell = OnceCell::new_with(Some(1));
+/// cell.set(2).or_already_set(); // panics, cell already initialized
+/// ```
+///
+/// [`OnceCell`]: tokio::sync::OnceCell
+#[cfg(feature = "tokio")]
+pub trait OrAlreadySet {
+    type Value;
+
+    #[track_caller]
+    fn or_already_set(self) -> Self::Value;
+}
+
+/// Sets a cell that should only be set once, panicking if it already
+/// has a value.
+///
+/// This covers std's [`OnceLock`], whose `set` returns the rejected
+/// value as a bare `Err(T)` that `OrAlreadySet` can't tell apart
+/// from other errors. tokio's `OnceCell` gets it too, for code that is
+/// generic over both.
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use semtraits::SetOr;
+///
+/// let cell = OnceLock::new();
+/// cell.set_or_already_set(1);
+/// assert_eq!(cell.get(), Some(&1));
+/// ```
+///
+/// ```should_panic
+/// use std::sync::OnceLock;
+/// use semtraits::SetOr;
+///
+/// let cell = OnceLock::from(1);
+/// cell.set_or_already_set(2); // panics, cell already initialized
+/// ```
+///
+/// [`OnceLock`]: std::sync::OnceLock
+pub trait SetOr {
+    type Value;
+
+    #[track_caller]
+    fn set_or_already_set(&self, value: Self::Value);
+}
+
+/// Initializes a tokio [`OnceCell`] with a fallible future, leaving it
+/// empty if the future fails.
+///
+/// This is the async version of [`GetOrTryInit`]. Only one initializer
+/// runs at a time, and the others wait to see if it succeeds.
+///
+/// ```
+/// use tokio::sync::OnceCell;
+/// use semtraits::AsyncGetOrTryInit;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let token: OnceCell<String> = OnceCell::new();
+/// let failed = token.or_try_init(|| async { Err("auth server down") }).await;
+/// assert_eq!(failed, Err("auth server down"));
+/// assert!(!token.initialized());
+///
+/// let value = token
+///     .or_try_init(|| async { Ok::<_, &str>(String::from("secret")) })
+///     .await;
+/// assert_eq!(value.map(String::as_str), Ok("secret"));
+/// # }
+/// ```
+///
+/// [`OnceCell`]: tokio::sync::OnceCell
+#[cfg(feature = "tokio")]
+pub trait AsyncGetOrTryInit {
+    type Value;
+
+    /// Gets the value, awaiting the future from `f` to initialize the
+    /// cell if it is empty.
+    fn or_try_init<E, F, Fut>(&self, f: F) -> impl Future<Output = Result<&Self::Value, E>> + Send
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<Self::Value, E>> + Send;
+}
@@ -0,0 +1,99 @@
+//! Locks that can be swapped out without changing the code using them.
+//!
+//! Generic code takes a lock through one of these traits, and the lock
+//! backend is picked by whoever constructs it.
+//!
+//! ```
+//! use std::sync::Mutex;
+//! use semtraits::lock::Lock;
+//!
+//! fn push_twice<L: Lock<Target = Vec<i32>>>(list: &L, value: i32) {
+//!     let mut guard = list.lock();
+//!     guard.push(value);
+//!     guard.push(value);
+//! }
+//!
+//! let list = Mutex::new(Vec::new());
+//! push_twice(&list, 1);
+//! assert_eq!(*list.lock().unwrap(), [1, 1]);
+//! ```
+//!
+//! Lock types usually have inherent methods with the same names, which
+//! take precedence when calling on a concrete type. These traits are
+//! meant to be used through generics, or called as `Lock::lock(&mutex)`.
+//!
+//! # Poisoning
+//!
+//! Only the std locks can be poisoned. Their impls panic on a poisoned
+//! lock like [`or_poisoned`](crate::OrPoisoned::or_poisoned), so the
+//! guards are the same across backends. The parking_lot and tokio locks
+//! are never poisoned: a panic while holding the lock releases it
+//! as-is.
+//!
+//! # Implementations
+//!
+//! | Trait                  | std       | `parking_lot` | `tokio`   |
+//! |------------------------|-----------|---------------|-----------|
+//! | [`Lock`]               | `Mutex`   | `Mutex`       |           |
+//! | [`ReadWriteLock`]      | `RwLock`  | `RwLock`      |           |
+//! | [`AsyncLock`]          |           |               | `Mutex`   |
+//! | [`AsyncReadWriteLock`] |           |               | `RwLock`  |
+
+use core::ops::{Deref, DerefMut};
+
+/// A lock giving exclusive access to its data.
+pub trait Lock {
+    type Target: ?Sized;
+    type Guard<'a>: DerefMut<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Blocks the current thread until the lock is acquired.
+    fn lock(&self) -> Self::Guard<'_>;
+}
+
+/// A lock allowing many readers or a single writer.
+pub trait ReadWriteLock {
+    type Target: ?Sized;
+    type ReadGuard<'a>: Deref<Target = Self::Target>
+    where
+        Self: 'a;
+    type WriteGuard<'a>: DerefMut<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Blocks the current thread until shared access is acquired.
+    fn read(&self) -> Self::ReadGuard<'_>;
+
+    /// Blocks the current thread until exclusive access is acquired.
+    fn write(&self) -> Self::WriteGuard<'_>;
+}
+
+/// A lock giving exclusive access to its data, waiting asynchronously.
+pub trait AsyncLock {
+    type Target: ?Sized;
+    type Guard<'a>: DerefMut<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Waits until the lock is acquired.
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>> + Send;
+}
+
+/// A lock allowing many readers or a single writer, waiting
+/// asynchronously.
+pub trait AsyncReadWriteLock {
+    type Target: ?Sized;
+    type ReadGuard<'a>: Deref<Target = Self::Target>
+    where
+        Self: 'a;
+    type WriteGuard<'a>: DerefMut<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Waits until shared access is acquired.
+    fn read(&self) -> impl Future<Output = Self::ReadGuard<'_>> + Send;
+
+    /// Waits until exclusive access is acquired.
+    fn write(&self) -> impl Future<Output = Self::WriteGuard<'_>> + Send;
+}
@@ -0,0 +1,78 @@
+use core::fmt;
+use std::{
+    sync::{Arc, Mutex},
+    vec::Vec,
+};
+
+use tokio::task::AbortHandle;
+
+use crate::{OrPoisoned, Share};
+
+/// Abort handles collected from around a program, so that every
+/// registered task can be cancelled together on shutdown.
+///
+/// ```
+/// use semtraits::{Share, shared::AbortRegistry};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let registry = AbortRegistry::new();
+/// let worker = tokio::spawn(std::future::pending::<()>());
+/// registry.share().register(worker.abort_handle());
+///
+/// registry.abort_all();
+/// assert!(worker.await.unwrap_err().is_cancelled());
+/// # }
+/// ```
+#[derive(Default)]
+pub struct AbortRegistry(Arc<Mutex<Vec<AbortHandle>>>);
+
+impl AbortRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&self, handle: AbortHandle) {
+        let mut handles = self.0.lock().or_poisoned();
+        // don't hold on to handles of tasks that have already finished.
+        handles.retain(|handle| !handle.is_finished());
+        handles.push(handle);
+    }
+
+    /// Aborts every registered task, leaving the registry empty.
+    ///
+    /// Tasks are only cancelled at their next await point, so some may
+    /// still be running when this returns.
+    pub fn abort_all(&self) {
+        let handles = core::mem::take(&mut *self.0.lock().or_poisoned());
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    /// The number of registered tasks, including ones that may have
+    /// finished since they were registered.
+    pub fn len(&self) -> usize {
+        self.0.lock().or_poisoned().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Debug for AbortRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortRegistry")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl Clone for AbortRegistry {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for AbortRegistry {}
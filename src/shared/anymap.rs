@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex};
+
+use anymap::{Map, any::Any};
+
+use crate::{OrPoisoned, Share};
+
+/// A map of values keyed by their type, shared between threads.
+///
+/// Values must be [`Send`] to be shared between threads, so this
+/// wraps an [`anymap::Map<dyn Any + Send>`](anymap::Map) rather than
+/// an [`anymap::AnyMap`].
+///
+/// ```
+/// use std::thread;
+/// use semtraits::{Share, shared::SharedAnyMap};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// let map = SharedAnyMap::new();
+/// let writer = map.share();
+/// thread::spawn(move || {
+///     writer.insert(Config { verbose: true });
+///     writer.insert(5u32);
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(map.get::<Config>(), Some(Config { verbose: true }));
+/// assert_eq!(map.get::<u32>(), Some(5));
+/// assert_eq!(map.get::<String>(), None);
+/// ```
+#[derive(Debug)]
+pub struct SharedAnyMap(Arc<Mutex<Map<dyn Any + Send>>>);
+
+impl SharedAnyMap {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(Map::new())))
+    }
+
+    /// Inserts a value, returning the previous value of the same type
+    /// if there was one.
+    pub fn insert<T: Send + 'static>(&self, value: T) -> Option<T> {
+        self.0.lock().or_poisoned().insert(value)
+    }
+
+    /// Gets a clone of the value of type `T`.
+    ///
+    /// The value is cloned so that the map is not kept locked.
+    pub fn get<T: Clone + Send + 'static>(&self) -> Option<T> {
+        self.0.lock().or_poisoned().get::<T>().cloned()
+    }
+}
+
+impl Default for SharedAnyMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for SharedAnyMap {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for SharedAnyMap {}
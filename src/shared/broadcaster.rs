@@ -0,0 +1,78 @@
+use core::fmt;
+use std::{
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    vec::Vec,
+};
+
+use crate::{OrPoisoned, Share};
+
+/// Sends a copy of every published value to each subscriber, built from
+/// one [`mpsc`] channel per subscriber.
+///
+/// Subscribers unsubscribe by dropping their receiver. Channels are
+/// unbounded, so a subscriber that never receives keeps every value.
+///
+/// ```
+/// use semtraits::{Share, shared::Broadcaster};
+///
+/// let broadcaster = Broadcaster::new();
+/// let a = broadcaster.subscribe();
+/// let b = broadcaster.share().subscribe();
+/// broadcaster.publish(1);
+/// assert_eq!(a.recv(), Ok(1));
+/// assert_eq!(b.recv(), Ok(1));
+/// ```
+pub struct Broadcaster<T>(Arc<Mutex<Vec<Sender<T>>>>);
+
+impl<T: Clone> Broadcaster<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes to values published after this call.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (tx, rx) = mpsc::channel();
+        self.0.lock().or_poisoned().push(tx);
+        rx
+    }
+
+    /// Sends `value` to every subscriber, forgetting the ones whose
+    /// receiver has been dropped.
+    pub fn publish(&self, value: T) {
+        self.0
+            .lock()
+            .or_poisoned()
+            .retain(|tx| tx.send(value.clone()).is_ok());
+    }
+
+    /// The number of subscribers, including ones that have gone away
+    /// since the last [`publish`](Self::publish).
+    pub fn subscriber_count(&self) -> usize {
+        self.0.lock().or_poisoned().len()
+    }
+}
+
+impl<T> Default for Broadcaster<T> {
+    fn default() -> Self {
+        Self(Arc::default())
+    }
+}
+
+impl<T> fmt::Debug for Broadcaster<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Broadcaster")
+            .field("subscribers", &self.0.lock().or_poisoned().len())
+            .finish()
+    }
+}
+
+impl<T> Clone for Broadcaster<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Share for Broadcaster<T> {}
@@ -0,0 +1,74 @@
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use crate::{OrPoisoned, Share};
+
+/// A read-mostly value that is replaced as a whole rather than mutated.
+///
+/// Readers [`load`](Self::load) the current version and keep it for as
+/// long as they like, while [`update`](Self::update) builds the next
+/// version from a copy. A reader never sees a half-updated value.
+///
+/// ```
+/// use semtraits::{Share, shared::SharedCow};
+///
+/// let routes = SharedCow::new(vec!["/"]);
+/// let before = routes.load();
+/// routes.share().update(|routes| {
+///     let mut routes = routes.clone();
+///     routes.push("/about");
+///     routes
+/// });
+/// assert_eq!(*before, ["/"]);
+/// assert_eq!(*routes.load(), ["/", "/about"]);
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedCow<T>(Arc<Inner<T>>);
+
+#[derive(Debug, Default)]
+struct Inner<T> {
+    current: RwLock<Arc<T>>,
+    // held for a whole update, so that updates don't overwrite each other.
+    updating: Mutex<()>,
+}
+
+impl<T> SharedCow<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Inner {
+            current: RwLock::new(Arc::new(value)),
+            updating: Mutex::new(()),
+        }))
+    }
+
+    /// Returns the current version.
+    ///
+    /// Later updates do not affect the returned value.
+    pub fn load(&self) -> Arc<T> {
+        Arc::clone(&self.0.current.read().or_poisoned())
+    }
+
+    /// Replaces the value with the one that `f` builds from the current
+    /// version.
+    ///
+    /// Updates from different handles run one at a time, so none of them
+    /// are lost. Readers keep loading the previous version while `f`
+    /// runs, and are only blocked while the new version is stored.
+    pub fn update(&self, f: impl FnOnce(&T) -> T) {
+        // a panicking `f` poisons this lock without touching the value,
+        // so later updates can go ahead.
+        let _updating = self
+            .0
+            .updating
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let next = Arc::new(f(&self.load()));
+        *self.0.current.write().or_poisoned() = next;
+    }
+}
+
+impl<T> Clone for SharedCow<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Share for SharedCow<T> {}
@@ -0,0 +1,100 @@
+use core::{borrow::Borrow, fmt};
+use std::sync::Arc;
+
+use crossbeam_skiplist::{SkipMap, map::Entry};
+
+use crate::Share;
+
+/// A lock-free ordered [`SkipMap`] that shares its entries when cloned.
+///
+/// An `Arc<SkipMap<K, V>>` works just as well, this only saves writing
+/// out the `Arc`.
+///
+/// ```
+/// use std::thread;
+/// use semtraits::{Share, shared::SharedSkipMap};
+///
+/// let map = SharedSkipMap::new();
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let map = map.share();
+///         thread::spawn(move || {
+///             map.insert(i, i * 10);
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(*map.get(&2).unwrap().value(), 20);
+/// assert_eq!(*map.remove(&3).unwrap().value(), 30);
+/// assert!(map.get(&3).is_none());
+/// ```
+pub struct SharedSkipMap<K, V>(Arc<SkipMap<K, V>>);
+
+impl<K, V> SharedSkipMap<K, V> {
+    pub fn new() -> Self {
+        Self(Arc::new(SkipMap::new()))
+    }
+}
+
+impl<K: Ord, V> SharedSkipMap<K, V> {
+    /// See [`SkipMap::get`].
+    pub fn get<Q>(&self, key: &Q) -> Option<Entry<'_, K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get(key)
+    }
+}
+
+impl<K, V> SharedSkipMap<K, V>
+where
+    K: Ord + Send + 'static,
+    V: Send + 'static,
+{
+    /// Inserts a key-value pair, replacing the previous value for
+    /// that key if there was one.
+    ///
+    /// See [`SkipMap::insert`].
+    pub fn insert(&self, key: K, value: V) -> Entry<'_, K, V> {
+        self.0.insert(key, value)
+    }
+
+    /// See [`SkipMap::remove`].
+    pub fn remove<Q>(&self, key: &Q) -> Option<Entry<'_, K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.remove(key)
+    }
+}
+
+impl<K, V> Default for SharedSkipMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> From<SkipMap<K, V>> for SharedSkipMap<K, V> {
+    fn from(map: SkipMap<K, V>) -> Self {
+        Self(Arc::new(map))
+    }
+}
+
+impl<K, V> Clone for SharedSkipMap<K, V> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for SharedSkipMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedSkipMap").field(&self.0).finish()
+    }
+}
+
+impl<K, V> Share for SharedSkipMap<K, V> {}
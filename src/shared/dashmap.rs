@@ -0,0 +1,90 @@
+use core::{borrow::Borrow, fmt, hash::Hash};
+use std::sync::Arc;
+
+use dashmap::{DashMap, mapref::one::Ref};
+
+use crate::Share;
+
+/// A concurrent [`DashMap`] that shares its entries when cloned.
+///
+/// Cloning a [`DashMap`] directly copies every entry. Clones of a
+/// [`SharedDashMap`] all point to the same map instead.
+///
+/// ```
+/// use std::thread;
+/// use semtraits::{Share, shared::SharedDashMap};
+///
+/// let map = SharedDashMap::new();
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let map = map.share();
+///         thread::spawn(move || map.insert(i, i * 10))
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(*map.get(&2).unwrap(), 20);
+/// assert_eq!(map.remove(&3), Some((3, 30)));
+/// assert!(map.get(&3).is_none());
+/// ```
+pub struct SharedDashMap<K, V>(Arc<DashMap<K, V>>);
+
+impl<K: Eq + Hash, V> SharedDashMap<K, V> {
+    pub fn new() -> Self {
+        Self(Arc::new(DashMap::new()))
+    }
+
+    /// Inserts a key-value pair, returning the previous value for that
+    /// key if there was one.
+    ///
+    /// See [`DashMap::insert`].
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// See [`DashMap::get`].
+    pub fn get<Q>(&self, key: &Q) -> Option<Ref<'_, K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.get(key)
+    }
+
+    /// See [`DashMap::remove`].
+    pub fn remove<Q>(&self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.remove(key)
+    }
+}
+
+impl<K: Eq + Hash, V> Default for SharedDashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> From<DashMap<K, V>> for SharedDashMap<K, V> {
+    fn from(map: DashMap<K, V>) -> Self {
+        Self(Arc::new(map))
+    }
+}
+
+impl<K, V> Clone for SharedDashMap<K, V> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<K: Eq + Hash + fmt::Debug, V: fmt::Debug> fmt::Debug for SharedDashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedDashMap").field(&self.0).finish()
+    }
+}
+
+impl<K, V> Share for SharedDashMap<K, V> {}
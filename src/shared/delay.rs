@@ -0,0 +1,38 @@
+use core::time::Duration;
+
+use futures::future::Shared;
+use tokio::time::Sleep;
+
+use crate::ShareFuture;
+
+/// A delay that can be awaited from many tasks, all completing at the
+/// same deadline.
+///
+/// There is only one timer no matter how many times the delay is
+/// [shared](crate::Share), instead of a timer for each waiting task.
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio::time::Instant;
+/// use semtraits::{Share, shared::delay_shared};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let start = Instant::now();
+/// let deadline = delay_shared(Duration::from_secs(1));
+/// let waiters: Vec<_> = (0..3).map(|_| tokio::spawn(deadline.share())).collect();
+/// for waiter in waiters {
+///     waiter.await.unwrap();
+/// }
+/// assert_eq!(start.elapsed(), Duration::from_secs(1));
+/// # }
+/// ```
+///
+/// The timer starts when this is called, not when it is first awaited.
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime.
+pub fn delay_shared(duration: Duration) -> Shared<Sleep> {
+    tokio::time::sleep(duration).share_future()
+}
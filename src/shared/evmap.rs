@@ -0,0 +1,54 @@
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    ops::Deref,
+};
+
+use evmap::MapReadRef;
+
+/// A [`Snapshot`](crate::Snapshot) of an evmap.
+///
+/// This derefs to the [`MapReadRef`] taken from the read handle, so
+/// it can be used wherever generic code expects a snapshot guard.
+///
+/// ```
+/// use semtraits::Snapshot;
+///
+/// let (read, mut write) = evmap::new::<&str, i32>();
+/// write.insert("a", 1).refresh();
+///
+/// let map = read.snapshot().unwrap();
+/// assert_eq!(map.get_one("a"), Some(&1));
+/// assert_eq!(map.len(), 1);
+/// ```
+pub struct MapSnapshot<'a, K, V, M = (), S = std::hash::RandomState>(
+    pub(crate) MapReadRef<'a, K, V, M, S>,
+)
+where
+    K: Eq + Hash,
+    V: Eq + Hash,
+    S: BuildHasher;
+
+impl<'a, K, V, M, S> Deref for MapSnapshot<'a, K, V, M, S>
+where
+    K: Eq + Hash,
+    V: Eq + Hash,
+    S: BuildHasher,
+{
+    type Target = MapReadRef<'a, K, V, M, S>;
+
+    fn deref(&self) -> &MapReadRef<'a, K, V, M, S> {
+        &self.0
+    }
+}
+
+impl<K, V, M, S> fmt::Debug for MapSnapshot<'_, K, V, M, S>
+where
+    K: Eq + Hash + fmt::Debug,
+    V: Eq + Hash + fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
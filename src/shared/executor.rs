@@ -0,0 +1,72 @@
+use core::{fmt, ops::Deref};
+use std::sync::Arc;
+
+use smol::Executor;
+
+use crate::{Share, spawn::Spawn};
+
+/// A smol [`Executor`] that is shared when cloned.
+///
+/// Tasks can be [`spawn_detached`](Spawn::spawn_detached) from any
+/// handle, and they are run by whichever threads are driving the
+/// executor with [`Executor::run`] (reachable through [`Deref`]).
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use semtraits::{Share, shared::SharedExecutor, spawn::Spawn};
+///
+/// let executor = SharedExecutor::new();
+/// let count = Arc::new(AtomicUsize::new(0));
+/// let (tx, rx) = smol::channel::unbounded();
+///
+/// let spawner = executor.share();
+/// let (count1, tx1) = (count.share(), tx.share());
+/// spawner.spawn_detached(async move {
+///     count1.fetch_add(1, Ordering::Relaxed);
+///     tx1.send(()).await.unwrap();
+/// });
+///
+/// smol::block_on(executor.run(rx.recv())).unwrap();
+/// assert_eq!(count.load(Ordering::Relaxed), 1);
+/// ```
+#[derive(Default)]
+pub struct SharedExecutor(Arc<Executor<'static>>);
+
+impl SharedExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Deref for SharedExecutor {
+    type Target = Executor<'static>;
+
+    fn deref(&self) -> &Executor<'static> {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SharedExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedExecutor").field(&self.0).finish()
+    }
+}
+
+impl Clone for SharedExecutor {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for SharedExecutor {}
+
+impl<F> Spawn<F> for SharedExecutor
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn spawn_detached(&self, task: F) {
+        self.0.spawn(task).detach();
+    }
+}
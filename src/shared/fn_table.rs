@@ -0,0 +1,75 @@
+use core::fmt;
+use std::{boxed::Box, sync::Arc, vec::Vec};
+
+use crate::Share;
+
+type Callback<A, R> = Box<dyn Fn(A) -> R + Send + Sync>;
+
+/// A fixed list of callbacks that is shared when cloned.
+///
+/// The callbacks are collected up front, and can then be dispatched to
+/// from any number of shared handles.
+///
+/// ```
+/// use semtraits::{Share, shared::SharedFnTable};
+///
+/// let table: SharedFnTable<i32, i32> = SharedFnTable::new(vec![
+///     Box::new(|x| x + 1),
+///     Box::new(|x| x * 2),
+/// ]);
+/// let other = table.share();
+/// assert_eq!(other.call_all(5), [6, 10]);
+/// ```
+pub struct SharedFnTable<A, R>(Arc<Vec<Callback<A, R>>>);
+
+impl<A, R> SharedFnTable<A, R> {
+    pub fn new(fns: Vec<Callback<A, R>>) -> Self {
+        Self(Arc::new(fns))
+    }
+
+    /// Calls every callback in order with a clone of `arg`, collecting
+    /// their results.
+    pub fn call_all(&self, arg: A) -> Vec<R>
+    where
+        A: Clone,
+    {
+        self.0.iter().map(|f| f(arg.clone())).collect()
+    }
+
+    /// The number of callbacks in the table.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<A, R> FromIterator<Callback<A, R>> for SharedFnTable<A, R> {
+    fn from_iter<I: IntoIterator<Item = Callback<A, R>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<A, R> fmt::Debug for SharedFnTable<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedFnTable")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, R> Default for SharedFnTable<A, R> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<A, R> Clone for SharedFnTable<A, R> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<A, R> Share for SharedFnTable<A, R> {}
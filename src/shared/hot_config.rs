@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use tokio::sync::watch;
+
+use crate::Share;
+
+/// A configuration value that can be swapped out while it is in use.
+///
+/// Readers get the [`current`](HotConfig::current) value without locking,
+/// and can [`subscribe`](HotConfig::subscribe) to be woken whenever it is
+/// [`reload`](HotConfig::reload)ed. Cloning the handle shares the same
+/// configuration.
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use semtraits::{Share, shared::HotConfig};
+///
+/// let config = HotConfig::new(String::from("v1"));
+/// let mut changes = config.subscribe();
+///
+/// let writer = config.share();
+/// tokio::spawn(async move { writer.reload(String::from("v2")) });
+///
+/// changes.changed().await.unwrap();
+/// assert_eq!(*config.current(), "v2");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HotConfig<T>(Arc<Inner<T>>);
+
+#[derive(Debug)]
+struct Inner<T> {
+    current: ArcSwap<T>,
+    notify: watch::Sender<()>,
+}
+
+impl<T> HotConfig<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Inner {
+            current: ArcSwap::from_pointee(value),
+            notify: watch::Sender::new(()),
+        }))
+    }
+
+    /// Returns the configuration at this point in time.
+    ///
+    /// Later reloads do not affect the returned value.
+    pub fn current(&self) -> Arc<T> {
+        self.0.current.load_full()
+    }
+
+    /// Replaces the configuration and notifies all subscribers.
+    pub fn reload(&self, new: T) {
+        self.0.current.store(Arc::new(new));
+        // there may be no subscribers yet; they will see the new value
+        // through `current` anyway.
+        self.0.notify.send_replace(());
+    }
+
+    /// Returns a receiver that is marked as changed on every reload.
+    ///
+    /// The receiver starts off seeing the configuration as unchanged.
+    pub fn subscribe(&self) -> watch::Receiver<()> {
+        self.0.notify.subscribe()
+    }
+}
+
+impl<T: Default> Default for HotConfig<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Clone for HotConfig<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Share for HotConfig<T> {}
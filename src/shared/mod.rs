@@ -0,0 +1,91 @@
+//! Ready-made types for working with shared data.
+//!
+//! Most of these wrap a type that would otherwise be deep-cloned (or
+//! not cloneable at all) behind a reference count, so that cloning a
+//! handle [shares](crate::Share) the same underlying data.
+
+#[cfg(feature = "tokio")]
+mod abort_registry;
+#[cfg(feature = "anymap")]
+mod anymap;
+#[cfg(feature = "std")]
+mod broadcaster;
+#[cfg(feature = "std")]
+mod cow;
+#[cfg(feature = "crossbeam-skiplist")]
+mod crossbeam_skiplist;
+#[cfg(feature = "dashmap")]
+mod dashmap;
+#[cfg(all(feature = "futures", feature = "tokio"))]
+mod delay;
+#[cfg(feature = "evmap")]
+mod evmap;
+#[cfg(feature = "smol")]
+mod executor;
+#[cfg(feature = "std")]
+mod fn_table;
+#[cfg(all(feature = "arc-swap", feature = "tokio"))]
+mod hot_config;
+#[cfg(feature = "tokio")]
+mod notify;
+#[cfg(feature = "std")]
+mod observer_set;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "std")]
+mod resource;
+#[cfg(feature = "std")]
+mod scoped;
+#[cfg(feature = "tokio")]
+mod semaphore;
+#[cfg(feature = "std")]
+mod share_cell;
+#[cfg(feature = "futures")]
+mod shared_stream;
+#[cfg(feature = "tokio")]
+mod topic;
+#[cfg(feature = "weak-table")]
+mod weak_table;
+
+#[cfg(feature = "tokio")]
+pub use abort_registry::AbortRegistry;
+#[cfg(feature = "anymap")]
+pub use anymap::SharedAnyMap;
+#[cfg(feature = "std")]
+pub use broadcaster::Broadcaster;
+#[cfg(feature = "std")]
+pub use cow::SharedCow;
+#[cfg(feature = "crossbeam-skiplist")]
+pub use crossbeam_skiplist::SharedSkipMap;
+#[cfg(feature = "dashmap")]
+pub use dashmap::SharedDashMap;
+#[cfg(all(feature = "futures", feature = "tokio"))]
+pub use delay::delay_shared;
+#[cfg(feature = "evmap")]
+pub use evmap::MapSnapshot;
+#[cfg(feature = "smol")]
+pub use executor::SharedExecutor;
+#[cfg(feature = "std")]
+pub use fn_table::SharedFnTable;
+#[cfg(all(feature = "arc-swap", feature = "tokio"))]
+pub use hot_config::HotConfig;
+#[cfg(feature = "tokio")]
+pub use notify::SharedNotify;
+#[cfg(feature = "std")]
+pub use observer_set::ObserverSet;
+#[cfg(feature = "rand")]
+pub use rand::SharedRng;
+#[cfg(feature = "std")]
+pub use resource::SharedResource;
+#[cfg(feature = "std")]
+pub use scoped::ScopedShared;
+#[cfg(feature = "tokio")]
+pub use semaphore::SharedSemaphore;
+#[cfg(feature = "std")]
+pub use share_cell::ShareCell;
+#[cfg(feature = "futures")]
+pub use shared_stream::SharedStream;
+#[cfg(feature = "tokio")]
+pub use topic::{Subscription, Topic};
+#[cfg(feature = "weak-table")]
+pub use weak_table::SharedWeakCache;
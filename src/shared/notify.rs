@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+use crate::Share;
+
+/// A wakeup signal that is shared when cloned.
+///
+/// Every task that is waiting when [`wake_all`](Self::wake_all) is
+/// called is woken. Nothing is stored for later, so a task that starts
+/// waiting afterwards waits for the next wakeup.
+///
+/// ```
+/// use semtraits::{Share, shared::SharedNotify};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let notify = SharedNotify::new();
+/// let waiter = notify.share();
+/// let task = tokio::spawn(async move { waiter.wait().await });
+/// tokio::task::yield_now().await;
+///
+/// notify.wake_all();
+/// task.await.unwrap();
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedNotify(Arc<Notify>);
+
+impl SharedNotify {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wakes every task that is currently waiting.
+    pub fn wake_all(&self) {
+        self.0.notify_waiters();
+    }
+
+    /// Waits until the next [`wake_all`](Self::wake_all).
+    pub async fn wait(&self) {
+        self.0.notified().await;
+    }
+}
+
+impl Clone for SharedNotify {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for SharedNotify {}
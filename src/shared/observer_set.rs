@@ -0,0 +1,77 @@
+use std::{
+    sync::{self, Arc},
+    vec::Vec,
+};
+
+/// A set of observers that doesn't keep them alive.
+///
+/// Observers are stored as [`Weak`](sync::Weak) references, and are
+/// removed the next time the set is iterated after they have been
+/// dropped.
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use semtraits::{OrPoisoned, shared::ObserverSet};
+///
+/// let mut observers = ObserverSet::new();
+/// let a = Arc::new(Mutex::new(0));
+/// let b = Arc::new(Mutex::new(0));
+/// observers.register(&a);
+/// observers.register(&b);
+///
+/// observers.for_each_alive(|obs| *obs.lock().or_poisoned() += 1);
+/// assert_eq!(*a.lock().or_poisoned(), 1);
+///
+/// drop(b);
+/// assert_eq!(observers.len(), 2);
+/// observers.for_each_alive(|obs| *obs.lock().or_poisoned() += 1);
+/// assert_eq!(*a.lock().or_poisoned(), 2);
+/// assert_eq!(observers.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ObserverSet<T> {
+    observers: Vec<sync::Weak<T>>,
+}
+
+impl<T> ObserverSet<T> {
+    pub fn new() -> Self {
+        Self {
+            observers: Vec::new(),
+        }
+    }
+
+    /// Adds an observer without taking ownership of it.
+    pub fn register(&mut self, obs: &Arc<T>) {
+        self.observers.push(Arc::downgrade(obs));
+    }
+
+    /// Calls `f` on every observer that is still alive, removing the
+    /// ones that have been dropped.
+    pub fn for_each_alive(&mut self, mut f: impl FnMut(&Arc<T>)) {
+        self.observers.retain(|weak| match weak.upgrade() {
+            Some(obs) => {
+                f(&obs);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// The number of registered observers.
+    ///
+    /// This includes observers that have been dropped but not yet
+    /// removed by [`for_each_alive`](Self::for_each_alive).
+    pub fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+}
+
+impl<T> Default for ObserverSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::{Arc, Mutex};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{OrPoisoned, Share};
+
+/// A random number generator that can be shared between threads.
+///
+/// Every clone draws from the same underlying [`StdRng`], so a seeded
+/// [`SharedRng`] produces a single reproducible sequence no matter
+/// which handle each number is taken from.
+///
+/// ```
+/// use semtraits::{Share, shared::SharedRng};
+///
+/// let a = SharedRng::seed_from_u64(42);
+/// let b = a.share();
+/// let interleaved = [a.next_u64(), b.next_u64(), a.next_u64()];
+///
+/// let c = SharedRng::seed_from_u64(42);
+/// assert_eq!(interleaved, [c.next_u64(), c.next_u64(), c.next_u64()]);
+/// ```
+#[derive(Debug)]
+pub struct SharedRng(Arc<Mutex<StdRng>>);
+
+impl SharedRng {
+    pub fn new(rng: StdRng) -> Self {
+        Self(Arc::new(Mutex::new(rng)))
+    }
+
+    /// Creates a generator from a `u64` seed.
+    ///
+    /// See [`SeedableRng::seed_from_u64`].
+    pub fn seed_from_u64(seed: u64) -> Self {
+        Self::new(StdRng::seed_from_u64(seed))
+    }
+
+    /// Gets the next random `u64`, locking the generator for the
+    /// duration of the call.
+    pub fn next_u64(&self) -> u64 {
+        self.0.lock().or_poisoned().next_u64()
+    }
+}
+
+impl Clone for SharedRng {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for SharedRng {}
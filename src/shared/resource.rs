@@ -0,0 +1,99 @@
+use core::{fmt, ops::Deref};
+use std::{boxed::Box, sync::Arc};
+
+use crate::Share;
+
+type Cleanup<T> = Box<dyn FnOnce(&mut T) + Send + Sync>;
+
+/// A value that is shared when cloned, running a cleanup closure once
+/// the last handle is dropped.
+///
+/// This is for resources that need more than [`Drop`] when they are no
+/// longer used anywhere, like deregistering from a service or logging
+/// that a connection pool has shut down.
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use semtraits::{Share, shared::SharedResource};
+///
+/// let closed = Arc::new(Mutex::new(Vec::new()));
+/// let log = closed.share();
+/// let conn = SharedResource::with_cleanup(String::from("db"), move |name| {
+///     log.lock().unwrap().push(name.clone());
+/// });
+/// let other = conn.share();
+/// drop(conn);
+/// assert!(closed.lock().unwrap().is_empty());
+///
+/// drop(other);
+/// assert_eq!(*closed.lock().unwrap(), ["db"]);
+/// ```
+pub struct SharedResource<T>(Arc<Inner<T>>);
+
+struct Inner<T> {
+    value: T,
+    cleanup: Option<Cleanup<T>>,
+}
+
+impl<T> SharedResource<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Inner {
+            value,
+            cleanup: None,
+        }))
+    }
+
+    /// Creates a resource that calls `cleanup` with the value when the
+    /// last handle is dropped.
+    pub fn with_cleanup(value: T, cleanup: impl FnOnce(&mut T) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(Inner {
+            value,
+            cleanup: Some(Box::new(cleanup)),
+        }))
+    }
+
+    /// The number of handles to this resource.
+    pub fn handle_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        // the inner value is only dropped with the last handle.
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup(&mut self.value);
+        }
+    }
+}
+
+impl<T> Deref for SharedResource<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedResource<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedResource")
+            .field("value", &self.0.value)
+            .field("has_cleanup", &self.0.cleanup.is_some())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for SharedResource<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Clone for SharedResource<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Share for SharedResource<T> {}
@@ -0,0 +1,62 @@
+use std::thread::{Scope, ScopedJoinHandle};
+
+use crate::Share;
+
+/// A shared handle to give out to the threads of a [`thread::scope`].
+///
+/// Each thread started with [`spawn_with`](Self::spawn_with) gets its
+/// own [`share`](Share::share) of the handle, so there is no need to
+/// clone it into a variable before every spawn.
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+/// use semtraits::shared::ScopedShared;
+///
+/// let total = ScopedShared::new(Arc::new(AtomicUsize::new(0)));
+/// let inputs = [1, 2, 3];
+/// thread::scope(|scope| {
+///     for input in &inputs {
+///         total.spawn_with(scope, move |total| total.fetch_add(*input, Ordering::Relaxed));
+///     }
+/// });
+/// assert_eq!(total.get().load(Ordering::Relaxed), 6);
+/// ```
+///
+/// [`thread::scope`]: std::thread::scope
+#[derive(Debug, Clone, Default)]
+pub struct ScopedShared<T>(T);
+
+impl<T: Share> ScopedShared<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Spawns a scoped thread that is given a new share of the handle.
+    ///
+    /// See [`Scope::spawn`].
+    pub fn spawn_with<'scope, F, R>(
+        &self,
+        scope: &'scope Scope<'scope, '_>,
+        f: F,
+    ) -> ScopedJoinHandle<'scope, R>
+    where
+        T: Send + 'scope,
+        F: FnOnce(T) -> R + Send + 'scope,
+        R: Send + 'scope,
+    {
+        let shared = self.0.share();
+        scope.spawn(move || f(shared))
+    }
+}
+
+impl<T: Share> Share for ScopedShared<T> {}
@@ -0,0 +1,73 @@
+use core::fmt;
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{Share, impls::SEMAPHORE_CLOSED_PANIC_MESSAGE};
+
+/// A concurrency limiter that is shared when cloned.
+///
+/// Permits own a handle to the semaphore, so they can be moved into
+/// spawned tasks and are returned when dropped.
+///
+/// ```
+/// use semtraits::{Share, shared::SharedSemaphore};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let limit = SharedSemaphore::new(2);
+/// let permit = limit.share().acquire().await;
+/// assert_eq!(limit.available(), 1);
+///
+/// tokio::spawn(async move { drop(permit) }).await.unwrap();
+/// assert_eq!(limit.available(), 2);
+/// # }
+/// ```
+pub struct SharedSemaphore(Arc<Semaphore>);
+
+impl SharedSemaphore {
+    pub fn new(permits: usize) -> Self {
+        Self(Arc::new(Semaphore::new(permits)))
+    }
+
+    /// Waits for a permit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the semaphore is [`close`](Self::close)d, including
+    /// while waiting.
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        match Arc::clone(&self.0).acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => panic!("{SEMAPHORE_CLOSED_PANIC_MESSAGE}"),
+        }
+    }
+
+    /// The number of permits that can be acquired without waiting.
+    pub fn available(&self) -> usize {
+        self.0.available_permits()
+    }
+
+    /// Closes the semaphore for every handle, for shutting down.
+    ///
+    /// Permits that were already acquired are unaffected.
+    pub fn close(&self) {
+        self.0.close();
+    }
+}
+
+impl fmt::Debug for SharedSemaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSemaphore")
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+impl Clone for SharedSemaphore {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Share for SharedSemaphore {}
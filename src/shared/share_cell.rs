@@ -0,0 +1,69 @@
+use core::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+use crate::Share;
+
+/// A single-threaded mutable value that is shared when cloned.
+///
+/// This is an [`Rc<RefCell<T>>`], with the borrowing methods
+/// available directly on the handle.
+///
+/// ```
+/// use semtraits::{Share, shared::ShareCell};
+///
+/// let cell = ShareCell::new(vec![1]);
+/// let other = cell.share();
+/// other.borrow_mut().push(2);
+/// assert_eq!(*cell.borrow(), [1, 2]);
+///
+/// let _guard = cell.borrow_mut();
+/// assert!(other.try_borrow_mut().is_err());
+/// ```
+///
+/// Borrowing mutably while another borrow is active panics, like
+/// [`RefCell::borrow_mut`].
+///
+/// ```should_panic
+/// use semtraits::{Share, shared::ShareCell};
+///
+/// let cell = ShareCell::new(0);
+/// let other = cell.share();
+/// let _guard = cell.borrow_mut();
+/// *other.borrow_mut() += 1; // panics, already borrowed
+/// ```
+#[derive(Debug, Default)]
+pub struct ShareCell<T>(Rc<RefCell<T>>);
+
+impl<T> ShareCell<T> {
+    pub fn new(value: T) -> Self {
+        Self(Rc::new(RefCell::new(value)))
+    }
+
+    /// See [`RefCell::borrow`].
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.0.borrow()
+    }
+
+    /// See [`RefCell::borrow_mut`].
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        self.0.borrow_mut()
+    }
+
+    /// See [`RefCell::try_borrow`].
+    pub fn try_borrow(&self) -> Result<Ref<'_, T>, BorrowError> {
+        self.0.try_borrow()
+    }
+
+    /// See [`RefCell::try_borrow_mut`].
+    pub fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowMutError> {
+        self.0.try_borrow_mut()
+    }
+}
+
+impl<T> Clone for ShareCell<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T> Share for ShareCell<T> {}
@@ -0,0 +1,176 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+    vec::Vec,
+};
+
+use futures::Stream;
+
+use crate::{OrPoisoned, Share};
+
+/// A stream that yields the same items to every shared handle.
+///
+/// Items are polled from the underlying stream by whichever handle gets
+/// to them first, and buffered until every handle has seen them. A
+/// handle that falls behind keeps the items it hasn't seen yet in
+/// memory, so handles should be consumed at a similar rate or dropped.
+///
+/// A new handle starts from the same position as the one it was cloned
+/// from.
+///
+/// ```
+/// use futures::{StreamExt, executor::block_on, stream};
+/// use semtraits::{Share, ShareStream};
+///
+/// let mut first = stream::iter(0..4).share_stream();
+/// assert_eq!(block_on(first.next()), Some(0));
+///
+/// // only sees items after the first one.
+/// let second = first.share();
+/// assert_eq!(block_on(second.collect::<Vec<_>>()), [1, 2, 3]);
+/// assert_eq!(block_on(first.collect::<Vec<_>>()), [1, 2, 3]);
+/// ```
+///
+/// Create one with [`share_stream`](crate::ShareStream::share_stream).
+pub struct SharedStream<S: Stream> {
+    inner: Arc<Mutex<Inner<S>>>,
+    /// Index of the next item this handle will yield.
+    position: usize,
+}
+
+struct Inner<S: Stream> {
+    stream: Pin<Box<S>>,
+    buffer: VecDeque<S::Item>,
+    /// Index of the front of `buffer`.
+    offset: usize,
+    /// The number of handles at each position, so that items can be
+    /// dropped once the slowest handle has passed them.
+    cursors: BTreeMap<usize, usize>,
+    done: bool,
+    /// Handles waiting on an item that another handle is polling for.
+    wakers: Vec<Waker>,
+}
+
+impl<S: Stream> Inner<S> {
+    fn add_cursor(&mut self, position: usize) {
+        *self.cursors.entry(position).or_default() += 1;
+    }
+
+    fn remove_cursor(&mut self, position: usize) {
+        if let Some(count) = self.cursors.get_mut(&position) {
+            *count -= 1;
+            if *count == 0 {
+                self.cursors.remove(&position);
+            }
+        }
+        let slowest = self.cursors.keys().next().copied().unwrap_or(usize::MAX);
+        while self.offset < slowest && self.buffer.pop_front().is_some() {
+            self.offset += 1;
+        }
+    }
+
+    fn wake_all(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl<S: Stream> SharedStream<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        let mut cursors = BTreeMap::new();
+        cursors.insert(0, 1);
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                stream: Box::pin(stream),
+                buffer: VecDeque::new(),
+                offset: 0,
+                cursors,
+                done: false,
+                wakers: Vec::new(),
+            })),
+            position: 0,
+        }
+    }
+}
+
+impl<S: Stream> Stream for SharedStream<S>
+where
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let position = self.position;
+        let mut inner = self.inner.lock().or_poisoned();
+
+        let item = if let Some(item) = inner.buffer.get(position - inner.offset) {
+            item.clone()
+        } else if inner.done {
+            return Poll::Ready(None);
+        } else {
+            match inner.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    inner.buffer.push_back(item.clone());
+                    inner.wake_all();
+                    item
+                }
+                Poll::Ready(None) => {
+                    inner.done = true;
+                    inner.wake_all();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {
+                    // the underlying stream only wakes the latest poller,
+                    // so the others wait for it to pass the item on.
+                    if !inner.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                        inner.wakers.push(cx.waker().clone());
+                    }
+                    return Poll::Pending;
+                }
+            }
+        };
+
+        inner.add_cursor(position + 1);
+        inner.remove_cursor(position);
+        drop(inner);
+        self.position += 1;
+        Poll::Ready(Some(item))
+    }
+}
+
+impl<S: Stream> Clone for SharedStream<S> {
+    fn clone(&self) -> Self {
+        self.inner.lock().or_poisoned().add_cursor(self.position);
+        Self {
+            inner: Arc::clone(&self.inner),
+            position: self.position,
+        }
+    }
+}
+
+impl<S: Stream> Share for SharedStream<S> {}
+
+impl<S: Stream> Drop for SharedStream<S> {
+    fn drop(&mut self) {
+        // don't panic again if an item's clone panicked.
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        inner.remove_cursor(self.position);
+        // this handle may have been the one the underlying stream would
+        // wake, so let another handle take over polling it.
+        inner.wake_all();
+    }
+}
+
+impl<S: Stream> core::fmt::Debug for SharedStream<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedStream")
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
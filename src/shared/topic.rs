@@ -0,0 +1,83 @@
+use core::fmt;
+use std::sync::Arc;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{BroadcastSend, RecvSkipLagged, Share};
+
+/// A publish-subscribe topic that is shared when cloned.
+///
+/// Publishing never fails: a value published while nobody is
+/// subscribed is dropped. Subscribers that fall more than `capacity`
+/// values behind skip ahead instead of seeing an error.
+///
+/// ```
+/// use semtraits::{RecvSkipLagged, Share, shared::Topic};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let topic = Topic::new(16);
+/// let mut events = topic.subscribe();
+/// topic.share().publish("started");
+/// assert_eq!(events.recv_skip_lagged().await, Ok("started"));
+/// # }
+/// ```
+pub struct Topic<T>(Arc<broadcast::Sender<T>>);
+
+impl<T: Clone> Topic<T> {
+    /// Creates a topic that buffers up to `capacity` values for each
+    /// subscriber.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self(Arc::new(tx))
+    }
+
+    /// Sends `value` to every current subscriber.
+    pub fn publish(&self, value: T) {
+        // no subscribers is not an error for a topic.
+        self.0.send_to_any(value);
+    }
+
+    /// Subscribes to values published after this call.
+    pub fn subscribe(&self) -> Subscription<T> {
+        Subscription(self.0.subscribe())
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.0.receiver_count()
+    }
+}
+
+impl<T> fmt::Debug for Topic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Topic")
+            .field("subscribers", &self.0.receiver_count())
+            .finish()
+    }
+}
+
+impl<T> Clone for Topic<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Share for Topic<T> {}
+
+/// A subscriber to a [`Topic`].
+#[derive(Debug)]
+pub struct Subscription<T>(broadcast::Receiver<T>);
+
+/// Returns [`RecvError::Closed`] once every handle to the topic has been
+/// dropped and every value has been received.
+impl<T: Clone + Send> RecvSkipLagged for Subscription<T> {
+    type Item = T;
+
+    async fn recv_skip_lagged(&mut self) -> Result<T, RecvError> {
+        self.0.recv_skip_lagged().await
+    }
+}
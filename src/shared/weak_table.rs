@@ -0,0 +1,83 @@
+use core::{fmt, hash::Hash};
+use std::sync::{self, Arc, Mutex};
+
+use weak_table::WeakValueHashMap;
+
+use crate::{OrPoisoned, Share};
+
+/// A cache that hands out shared values without keeping them alive.
+///
+/// Values are only held weakly, so an entry is evicted once every
+/// [`Arc`] handed out for it has been dropped. The next lookup for that
+/// key creates the value again.
+///
+/// ```
+/// use std::sync::Arc;
+/// use semtraits::{Share, shared::SharedWeakCache};
+///
+/// let cache = SharedWeakCache::new();
+/// let a = cache.get_or_insert_with("a", || String::from("first"));
+/// let again = cache.share().get_or_insert_with("a", || String::from("second"));
+/// assert!(Arc::ptr_eq(&a, &again));
+///
+/// drop(a);
+/// drop(again);
+/// assert_eq!(*cache.get_or_insert_with("a", || String::from("third")), "third");
+/// ```
+pub struct SharedWeakCache<K, V>(Arc<Mutex<WeakValueHashMap<K, sync::Weak<V>>>>);
+
+impl<K: Eq + Hash, V> SharedWeakCache<K, V> {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(WeakValueHashMap::new())))
+    }
+
+    /// Returns the live value for `key`, or creates one with `f` if there
+    /// isn't one.
+    ///
+    /// The cache is locked while `f` runs, so `f` must not use the cache.
+    pub fn get_or_insert_with(&self, key: K, f: impl FnOnce() -> V) -> Arc<V> {
+        let mut map = self.0.lock().or_poisoned();
+        if let Some(value) = map.get(&key) {
+            return value;
+        }
+        let value = Arc::new(f());
+        map.insert(key, Arc::clone(&value));
+        value
+    }
+
+    /// Returns the value for `key` if it is still alive.
+    pub fn get(&self, key: &K) -> Option<Arc<V>> {
+        self.0.lock().or_poisoned().get(key)
+    }
+
+    /// The number of values that are still alive.
+    pub fn len(&self) -> usize {
+        let mut map = self.0.lock().or_poisoned();
+        map.remove_expired();
+        map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, V> Default for SharedWeakCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> fmt::Debug for SharedWeakCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedWeakCache").finish_non_exhaustive()
+    }
+}
+
+impl<K, V> Clone for SharedWeakCache<K, V> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<K, V> Share for SharedWeakCache<K, V> {}
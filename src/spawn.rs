@@ -0,0 +1,38 @@
+//! Fire-and-forget spawning across runtimes.
+//!
+//! Every runtime has its own way to start a background task, and a
+//! different way to let go of it afterwards: a dropped tokio
+//! `JoinHandle` detaches the task, while a dropped smol `Task` cancels
+//! it. [`Spawn`] gives them all the same detached behaviour.
+//!
+//! ```
+//! use std::sync::mpsc;
+//! use semtraits::{OrClosed, spawn::{Spawn, StdThreads}};
+//!
+//! fn start_worker(spawner: &impl Spawn<Box<dyn FnOnce() + Send>>, tx: mpsc::Sender<i32>) {
+//!     spawner.spawn_detached(Box::new(move || tx.send(1).or_closed()));
+//! }
+//!
+//! let (tx, rx) = mpsc::channel();
+//! start_worker(&StdThreads, tx);
+//! assert_eq!(rx.recv().or_closed(), 1);
+//! ```
+
+/// Something that can run a task of type `T` in the background.
+///
+/// `T` is a closure for thread pools and a future for async runtimes.
+///
+/// Implementations must:
+/// - start running `task` without waiting for it to finish,
+/// - keep running it to completion even though no handle to it is kept,
+/// - not report the task's output or panics back to the caller.
+///
+/// Use the runtime's own spawn method to wait for a task or cancel it.
+pub trait Spawn<T> {
+    fn spawn_detached(&self, task: T);
+}
+
+/// Spawns each task on a new [`std::thread`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdThreads;
@@ -0,0 +1,37 @@
+//! Helpers for testing [`Share`] impls.
+
+use crate::Share;
+
+/// Asserts that a [`share`](Share::share)d handle refers to the same
+/// state as `value`.
+///
+/// `eq` decides whether two handles share state, like [`Arc::ptr_eq`]
+/// or sending through one and receiving what was sent. Structural
+/// equality is not enough, since a deep clone would pass it too.
+///
+/// ```
+/// use std::sync::Arc;
+/// use semtraits::testing::assert_shares;
+///
+/// assert_shares(&Arc::new(1), Arc::ptr_eq);
+/// ```
+///
+/// ```should_panic
+/// use semtraits::{Share, testing::assert_shares};
+///
+/// #[derive(Clone)]
+/// struct Deep(Box<i32>);
+/// impl Share for Deep {}
+///
+/// assert_shares(&Deep(Box::new(1)), |a, b| core::ptr::eq(&*a.0, &*b.0)); // panics
+/// ```
+///
+/// [`Arc::ptr_eq`]: std::sync::Arc::ptr_eq
+#[track_caller]
+pub fn assert_shares<T: Share>(value: &T, eq: impl Fn(&T, &T) -> bool) {
+    let shared = value.share();
+    assert!(
+        eq(value, &shared),
+        "share() returned a handle that doesn't share state with the original"
+    );
+}
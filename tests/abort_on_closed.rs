@@ -0,0 +1,68 @@
+//! Each test re-runs itself in a child process, which is expected to
+//! abort instead of panicking.
+
+#![cfg(feature = "abort-on-closed")]
+
+use std::env;
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+
+use semtraits::{OrClosed, OrPoisoned};
+
+const CHILD_VAR: &str = "SEMTRAITS_ABORT_CHILD";
+
+/// Runs `test` again in a child process, returning its output there.
+///
+/// Returns `None` when already in the child.
+fn run_in_child(test: &str) -> Option<Output> {
+    if env::var_os(CHILD_VAR).is_some() {
+        return None;
+    }
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap();
+    Some(output)
+}
+
+fn assert_aborted(output: &Output, message: &str) {
+    assert!(!output.status.success());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        assert_eq!(output.status.signal(), Some(6), "expected SIGABRT");
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(message), "stderr: {stderr}");
+}
+
+#[test]
+fn closed_channel_aborts() {
+    match run_in_child("closed_channel_aborts") {
+        Some(output) => assert_aborted(&output, "receiving with no senders"),
+        None => {
+            let (tx, rx) = mpsc::channel::<i32>();
+            drop(tx);
+            rx.recv().or_closed();
+        }
+    }
+}
+
+#[test]
+fn poisoned_lock_aborts() {
+    match run_in_child("poisoned_lock_aborts") {
+        Some(output) => assert_aborted(&output, "lock poisoned"),
+        None => {
+            let mutex = Arc::new(Mutex::new(0));
+            let poisoner = Arc::clone(&mutex);
+            let _ = thread::spawn(move || {
+                let _guard = poisoner.lock().unwrap();
+                panic!("poisoning the lock");
+            })
+            .join();
+            drop(mutex.lock().or_poisoned());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "tokio")]
+
+use std::future;
+
+use semtraits::{Share, shared::AbortRegistry};
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn abort_all_cancels_every_registered_task() {
+    let registry = AbortRegistry::new();
+    let (started, mut started_rx) = mpsc::unbounded_channel();
+    // each part of the program registers its own tasks.
+    let tasks: Vec<_> = (0..4)
+        .map(|i| {
+            let registry = registry.share();
+            let started = started.share();
+            let task = tokio::spawn(async move {
+                started.send(i).unwrap();
+                future::pending::<()>().await
+            });
+            registry.register(task.abort_handle());
+            task
+        })
+        .collect();
+    drop(started);
+    for _ in 0..4 {
+        started_rx.recv().await.unwrap();
+    }
+    assert_eq!(registry.len(), 4);
+
+    registry.abort_all();
+    assert!(registry.is_empty());
+    for task in tasks {
+        assert!(task.await.unwrap_err().is_cancelled());
+    }
+}
+
+#[tokio::test]
+async fn finished_tasks_are_dropped_on_register() {
+    let registry = AbortRegistry::new();
+    let done = tokio::spawn(async {});
+    registry.register(done.abort_handle());
+    done.await.unwrap();
+
+    let pending = tokio::spawn(future::pending::<()>());
+    registry.register(pending.abort_handle());
+    assert_eq!(registry.len(), 1);
+
+    registry.share().abort_all();
+    assert!(pending.await.unwrap_err().is_cancelled());
+}
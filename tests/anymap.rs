@@ -0,0 +1,46 @@
+#![cfg(feature = "anymap")]
+
+use std::thread;
+
+use semtraits::{Share, shared::SharedAnyMap};
+
+#[test]
+fn heterogeneous_values_across_threads() {
+    let map = SharedAnyMap::new();
+    let handles = [
+        {
+            let map = map.share();
+            thread::spawn(move || {
+                map.insert(1u8);
+            })
+        },
+        {
+            let map = map.share();
+            thread::spawn(move || {
+                map.insert(String::from("hello"));
+            })
+        },
+        {
+            let map = map.share();
+            thread::spawn(move || {
+                map.insert(vec![1, 2, 3]);
+            })
+        },
+    ];
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(map.get::<u8>(), Some(1));
+    assert_eq!(map.get::<String>().as_deref(), Some("hello"));
+    assert_eq!(map.get::<Vec<i32>>(), Some(vec![1, 2, 3]));
+    assert_eq!(map.get::<u16>(), None);
+}
+
+#[test]
+fn insert_replaces_same_type() {
+    let map = SharedAnyMap::new();
+    assert_eq!(map.insert(1u32), None);
+    assert_eq!(map.share().insert(2u32), Some(1));
+    assert_eq!(map.get::<u32>(), Some(2));
+}
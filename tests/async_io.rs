@@ -0,0 +1,28 @@
+#![cfg(feature = "async-io")]
+
+use std::net::{TcpListener, TcpStream};
+
+use async_io::Async;
+use semtraits::CloneFd;
+
+#[test]
+fn clone_async_listener() {
+    async_io::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0)).unwrap();
+        let cloned = listener.clone_fd().unwrap();
+        assert_eq!(
+            listener.get_ref().local_addr().unwrap(),
+            cloned.get_ref().local_addr().unwrap()
+        );
+
+        let addr = listener.get_ref().local_addr().unwrap();
+        let client = Async::<TcpStream>::connect(addr).await.unwrap();
+        // the connection was made to the original, but is accepted
+        // through the clone.
+        let (server, _) = cloned.accept().await.unwrap();
+        assert_eq!(
+            server.get_ref().peer_addr().unwrap(),
+            client.get_ref().local_addr().unwrap()
+        );
+    });
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use std::sync::mpsc::TryRecvError;
+use std::thread;
+
+use semtraits::{Share, shared::Broadcaster};
+
+#[test]
+fn every_subscriber_receives_published_values() {
+    let broadcaster = Broadcaster::new();
+    let subscribers: Vec<_> = (0..3).map(|_| broadcaster.subscribe()).collect();
+
+    let publisher = broadcaster.share();
+    thread::spawn(move || publisher.publish(String::from("hello")))
+        .join()
+        .unwrap();
+    for rx in &subscribers {
+        assert_eq!(rx.try_recv().as_deref(), Ok("hello"));
+    }
+}
+
+#[test]
+fn late_subscribers_miss_earlier_values() {
+    let broadcaster = Broadcaster::new();
+    let early = broadcaster.subscribe();
+    broadcaster.publish(1);
+    let late = broadcaster.subscribe();
+    broadcaster.publish(2);
+    assert_eq!(early.try_iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(late.try_iter().collect::<Vec<_>>(), [2]);
+    assert_eq!(late.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[test]
+fn dropped_subscribers_are_pruned_on_publish() {
+    let broadcaster = Broadcaster::new();
+    let kept = broadcaster.subscribe();
+    drop(broadcaster.subscribe());
+    assert_eq!(broadcaster.subscriber_count(), 2);
+
+    broadcaster.publish(1);
+    assert_eq!(broadcaster.subscriber_count(), 1);
+    assert_eq!(kept.recv(), Ok(1));
+}
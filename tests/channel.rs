@@ -0,0 +1,146 @@
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel {
+    use std::thread;
+    use std::time::Duration;
+
+    use crossbeam_channel::{bounded, unbounded};
+    use semtraits::channel::{Either, recv_either};
+
+    #[test]
+    fn value_from_either_side() {
+        let (left, left_rx) = unbounded();
+        let (right, right_rx) = unbounded();
+        right.send("right").unwrap();
+        assert_eq!(recv_either(&left_rx, &right_rx), Either::Right("right"));
+
+        left.send(1).unwrap();
+        assert_eq!(recv_either(&left_rx, &right_rx), Either::Left(1));
+    }
+
+    #[test]
+    fn waits_for_a_value() {
+        let (left, left_rx) = bounded::<i32>(0);
+        let (right, right_rx) = bounded(0);
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            right.send(2).unwrap();
+            drop(left);
+        });
+        assert_eq!(recv_either(&left_rx, &right_rx), Either::Right(2));
+        sender.join().unwrap();
+    }
+
+    #[test]
+    fn closed_side_is_skipped() {
+        let (left, left_rx) = unbounded::<i32>();
+        let (right, right_rx) = unbounded();
+        drop(left);
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            right.send('r').unwrap();
+        });
+        // the left side is closed before the right gets a value.
+        assert_eq!(recv_either(&left_rx, &right_rx), Either::Right('r'));
+        sender.join().unwrap();
+    }
+
+    #[cfg(not(feature = "abort-on-closed"))]
+    #[test]
+    #[should_panic(expected = "receiving with no senders")]
+    fn both_closed() {
+        let (left, left_rx) = unbounded::<i32>();
+        let (right, right_rx) = unbounded::<i32>();
+        let closer = thread::spawn(move || {
+            drop(left);
+            thread::sleep(Duration::from_millis(20));
+            drop(right);
+        });
+        recv_either(&left_rx, &right_rx);
+        closer.join().unwrap();
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_mpsc {
+    use semtraits::{
+        Share,
+        channel::{fan_in, pipe_to},
+    };
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn pipe_forwards_until_source_closes() {
+        let (input, input_rx) = mpsc::channel(1);
+        let (output, mut output_rx) = mpsc::channel(1);
+        let pipe = tokio::spawn(pipe_to(input_rx, output));
+
+        let producer = input.share();
+        tokio::spawn(async move {
+            for i in 0..5 {
+                producer.send(i).await.unwrap();
+            }
+        });
+        drop(input);
+
+        let mut received = Vec::new();
+        while let Some(value) = output_rx.recv().await {
+            received.push(value);
+        }
+        assert_eq!(received, [0, 1, 2, 3, 4]);
+        pipe.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pipe_stops_when_destination_closes() {
+        let (input, input_rx) = mpsc::channel(4);
+        let (output, output_rx) = mpsc::channel(4);
+        drop(output_rx);
+        let pipe = tokio::spawn(pipe_to(input_rx, output));
+
+        input.send("dropped").await.unwrap();
+        pipe.await.unwrap();
+        // the pipe dropped its receiver when it returned.
+        assert!(input.is_closed());
+    }
+
+    #[tokio::test]
+    async fn fan_in_merges_every_input() {
+        let mut senders = Vec::new();
+        let mut receivers = Vec::new();
+        for _ in 0..3 {
+            let (tx, rx) = mpsc::channel(2);
+            senders.push(tx);
+            receivers.push(rx);
+        }
+        let mut merged = fan_in(receivers);
+
+        for (i, tx) in senders.into_iter().enumerate() {
+            tokio::spawn(async move {
+                for j in 0..4 {
+                    tx.send(i * 10 + j).await.unwrap();
+                }
+            });
+        }
+        let mut received = Vec::new();
+        while let Some(value) = merged.recv().await {
+            received.push(value);
+        }
+        received.sort();
+        assert_eq!(received, [0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23]);
+    }
+
+    #[tokio::test]
+    async fn fan_in_stays_open_until_the_last_input_closes() {
+        let (a, a_rx) = mpsc::channel(1);
+        let (b, b_rx) = mpsc::channel(1);
+        let mut merged = fan_in(vec![a_rx, b_rx]);
+        drop(a);
+        b.send("still open").await.unwrap();
+        assert_eq!(merged.recv().await, Some("still open"));
+
+        drop(b);
+        assert_eq!(merged.recv().await, None);
+        // no inputs at all is closed straight away.
+        assert_eq!(fan_in::<i32>(Vec::new()).recv().await, None);
+    }
+}
@@ -0,0 +1,86 @@
+//! A downstream error type gets `or_closed` through `ChannelClosed`.
+
+#[cfg(not(feature = "abort-on-closed"))]
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use semtraits::{ChannelClosed, OrClosed};
+
+enum LinkError {
+    Reset,
+    // only held so that it is dropped with the error.
+    #[allow(dead_code)]
+    Unsent(Payload),
+}
+
+impl ChannelClosed for LinkError {
+    fn describe_closed(&self) -> &'static str {
+        match self {
+            LinkError::Reset => "link reset",
+            LinkError::Unsent(_) => "link closed before sending",
+        }
+    }
+}
+
+struct Payload(Arc<AtomicBool>);
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    *payload.downcast::<String>().unwrap()
+}
+
+#[test]
+fn open_passes_value_through() {
+    let result: Result<i32, LinkError> = Ok(3);
+    assert_eq!(result.or_closed(), 3);
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[test]
+fn message_comes_from_the_error() {
+    let reset: Result<(), LinkError> = Err(LinkError::Reset);
+    let payload = panic::catch_unwind(|| reset.or_closed()).unwrap_err();
+    assert_eq!(panic_message(payload), "link reset");
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let unsent: Result<(), LinkError> = Err(LinkError::Unsent(Payload(dropped.clone())));
+    let payload = panic::catch_unwind(|| unsent.or_closed()).unwrap_err();
+    assert_eq!(panic_message(payload), "link closed before sending");
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[test]
+fn call_site_message_overrides() {
+    let reset: Result<(), LinkError> = Err(LinkError::Reset);
+    let payload = panic::catch_unwind(|| reset.or_closed_with("lost the uplink")).unwrap_err();
+    assert_eq!(panic_message(payload), "lost the uplink");
+}
+
+#[test]
+fn check_open_reports_the_message() {
+    let reset: Result<(), LinkError> = Err(LinkError::Reset);
+    assert_eq!(reset.check_open(), Err("link reset"));
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[test]
+fn side_specific_messages() {
+    let reset: Result<(), LinkError> = Err(LinkError::Reset);
+    let payload = panic::catch_unwind(|| reset.or_send_closed()).unwrap_err();
+    assert_eq!(panic_message(payload), "sending with disconnected receiver");
+
+    let reset: Result<(), LinkError> = Err(LinkError::Reset);
+    let payload = panic::catch_unwind(|| reset.or_recv_closed()).unwrap_err();
+    assert_eq!(panic_message(payload), "receiving with no senders");
+
+    let open: Result<i32, LinkError> = Ok(1);
+    assert_eq!(open.or_send_closed(), 1);
+}
@@ -0,0 +1,6 @@
+//! Helpers shared by the integration tests.
+
+use semtraits::Share;
+
+/// Fails to compile unless `T` implements [`Share`].
+pub fn assert_share<T: Share>() {}
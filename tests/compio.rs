@@ -0,0 +1,24 @@
+#![cfg(feature = "compio")]
+
+use compio::runtime::{CancelToken, Runtime};
+use semtraits::Share;
+
+#[test]
+fn shared_runtime_runs_spawned_tasks() {
+    let runtime = Runtime::new().unwrap();
+    let spawner = runtime.share();
+    let handle = spawner.spawn(async { 1 + 1 });
+    assert_eq!(runtime.block_on(handle).unwrap(), 2);
+}
+
+#[test]
+fn shared_cancel_token() {
+    let runtime = Runtime::new().unwrap();
+    runtime.block_on(async {
+        let token = CancelToken::new();
+        let other = token.share();
+        assert!(!token.is_cancelled());
+        other.cancel();
+        assert!(token.is_cancelled());
+    });
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use semtraits::{Share, shared::SharedCow};
+
+#[test]
+fn readers_never_see_a_torn_value() {
+    // both halves are always updated together.
+    let pair = SharedCow::new((0u64, 0u64));
+    let done = Arc::new(AtomicBool::new(false));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let pair = pair.share();
+            let done = done.share();
+            thread::spawn(move || {
+                loop {
+                    let (a, b) = *pair.load();
+                    assert_eq!(a, b);
+                    if done.load(Ordering::Relaxed) {
+                        break;
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..1000 {
+        pair.update(|&(a, b)| (a + 1, b + 1));
+    }
+    done.store(true, Ordering::Relaxed);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_eq!(*pair.load(), (1000, 1000));
+}
+
+#[test]
+fn concurrent_updates_are_not_lost() {
+    let list = SharedCow::new(Vec::new());
+    let barrier = Arc::new(Barrier::new(4));
+    let writers: Vec<_> = (0..4)
+        .map(|i| {
+            let list = list.share();
+            let barrier = barrier.share();
+            thread::spawn(move || {
+                barrier.wait();
+                for j in 0..25 {
+                    list.update(|list| {
+                        let mut list = list.clone();
+                        list.push(i * 100 + j);
+                        list
+                    });
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    assert_eq!(list.load().len(), 100);
+}
+
+#[test]
+fn loaded_versions_are_kept() {
+    let value = SharedCow::new(String::from("v1"));
+    let v1 = value.load();
+    value.update(|_| String::from("v2"));
+    assert_eq!(*v1, "v1");
+    assert_eq!(*value.load(), "v2");
+}
+
+#[test]
+fn panicking_update_leaves_the_value_usable() {
+    let count = SharedCow::new(0);
+    let panicking = count.share();
+    let result = thread::spawn(move || panicking.update(|_| panic!("update failed"))).join();
+    assert!(result.is_err());
+
+    assert_eq!(*count.load(), 0);
+    count.update(|n| n + 1);
+    assert_eq!(*count.load(), 1);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "crossbeam-skiplist")]
+
+mod common;
+
+use std::sync::Arc;
+use std::thread;
+
+use crossbeam_skiplist::SkipMap;
+use semtraits::{Share, shared::SharedSkipMap};
+
+use common::assert_share;
+
+#[test]
+fn arc_skip_map_is_share() {
+    assert_share::<Arc<SkipMap<u32, u32>>>();
+
+    let map = Arc::new(SkipMap::new());
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let map = map.share();
+            thread::spawn(move || {
+                for i in 0..50 {
+                    map.insert(t * 50 + i, t);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(map.len(), 400);
+}
+
+#[test]
+fn concurrent_inserts_through_shares() {
+    let map = SharedSkipMap::new();
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let map = map.share();
+            thread::spawn(move || {
+                for i in 0..50 {
+                    map.insert(t * 50 + i, t);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    for t in 0..8 {
+        for i in 0..50 {
+            assert_eq!(*map.get(&(t * 50 + i)).unwrap().value(), t);
+        }
+    }
+}
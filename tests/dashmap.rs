@@ -0,0 +1,43 @@
+#![cfg(feature = "dashmap")]
+
+use std::thread;
+
+use semtraits::{Share, shared::SharedDashMap};
+
+#[test]
+fn concurrent_inserts_through_shares() {
+    let map = SharedDashMap::new();
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let map = map.share();
+            thread::spawn(move || {
+                for i in 0..100 {
+                    map.insert(t * 100 + i, t);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    for t in 0..8 {
+        for i in 0..100 {
+            assert_eq!(*map.get(&(t * 100 + i)).unwrap(), t);
+        }
+    }
+}
+
+#[test]
+fn removes_are_visible_to_every_share() {
+    let map = SharedDashMap::new();
+    let other = map.share();
+    map.insert("a", 1);
+    assert_eq!(other.insert("a", 2), Some(1));
+
+    let remover = other.share();
+    thread::spawn(move || assert_eq!(remover.remove("a"), Some(("a", 2))))
+        .join()
+        .unwrap();
+    assert!(map.get("a").is_none());
+}
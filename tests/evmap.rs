@@ -0,0 +1,61 @@
+#![cfg(feature = "evmap")]
+
+use std::thread;
+
+use semtraits::{Share, Snapshot};
+
+#[test]
+fn snapshot_waits_for_first_refresh() {
+    let (read, mut write) = evmap::new::<&str, i32>();
+    write.insert("a", 1);
+    assert!(read.snapshot().is_none());
+
+    write.refresh();
+    assert_eq!(read.snapshot().unwrap().get_one("a"), Some(&1));
+}
+
+#[test]
+fn read_handles_across_threads_observe_writes() {
+    let (read, mut write) = evmap::new::<u32, u32>();
+    for i in 0..4 {
+        write.insert(i, i * 10);
+    }
+    write.refresh();
+
+    let factory = read.factory();
+    let readers: Vec<_> = (0..4)
+        .map(|i| {
+            let factory = factory.share();
+            thread::spawn(move || {
+                let read = factory.handle();
+                let map = read.snapshot().unwrap();
+                map.get_one(&i).copied()
+            })
+        })
+        .collect();
+    for (i, reader) in readers.into_iter().enumerate() {
+        assert_eq!(reader.join().unwrap(), Some(i as u32 * 10));
+    }
+
+    // a shared handle sees later refreshes too.
+    let other = read.share();
+    write.update(0, 99).refresh();
+    assert_eq!(other.snapshot().unwrap().get_one(&0).copied(), Some(99));
+    drop(write);
+    assert!(other.snapshot().is_none());
+}
+
+/// Reads through any snapshot, relying only on the guard's `Deref`.
+fn read_with<S: Snapshot, R>(handle: &S, f: impl FnOnce(&S::Target<'_>) -> R) -> Option<R> {
+    handle.snapshot().map(|guard| f(&guard))
+}
+
+#[test]
+fn generic_code_reads_through_the_guard() {
+    let (read, mut write) = evmap::new::<&str, i32>();
+    write.insert("a", 1).refresh();
+    assert_eq!(
+        read_with(&read, |map| map.get_one("a").copied()),
+        Some(Some(1))
+    );
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "flume")]
+
+use semtraits::{OrClosed, RecvOr, Share};
+
+#[tokio::test]
+async fn async_round_trip_between_tasks() {
+    let (tx, rx) = flume::bounded(1);
+    let producers: Vec<_> = (0..3)
+        .map(|i| {
+            let tx = tx.share();
+            tokio::spawn(async move { tx.send_async(i).await.or_closed() })
+        })
+        .collect();
+    drop(tx);
+
+    let mut received = Vec::new();
+    for _ in 0..3 {
+        received.push(rx.recv_async().await.or_closed());
+    }
+    for producer in producers {
+        producer.await.unwrap();
+    }
+    received.sort();
+    assert_eq!(received, [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn recv_or_falls_back_after_senders_drop() {
+    let (tx, rx) = flume::unbounded();
+    let tx1 = tx.share();
+    tx.send(1).or_closed();
+    drop(tx);
+    assert_eq!(rx.recv_async().await.recv_or(|| 0), 1);
+
+    drop(tx1);
+    assert_eq!(rx.recv_async().await.recv_or(|| -1), -1);
+    assert_eq!(rx.recv().recv_or(|| -2), -2);
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+#[should_panic(expected = "receiving with no senders")]
+async fn recv_async_after_senders_drop() {
+    let (tx, rx) = flume::unbounded::<i32>();
+    let tx1 = tx.share();
+    drop(tx);
+    drop(tx1);
+    rx.recv_async().await.or_closed();
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+#[should_panic(expected = "sending with disconnected receiver")]
+async fn send_async_after_receiver_drop() {
+    let (tx, rx) = flume::bounded(1);
+    drop(rx);
+    tx.send_async(1).await.or_closed();
+}
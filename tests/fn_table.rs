@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use semtraits::{Share, shared::SharedFnTable};
+
+#[test]
+fn call_all_from_many_threads() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counted = calls.share();
+    let table: SharedFnTable<usize, usize> = SharedFnTable::new(vec![
+        Box::new(|x| x + 1),
+        Box::new(|x| x * 10),
+        Box::new(move |x| {
+            counted.fetch_add(1, Ordering::Relaxed);
+            x
+        }),
+    ]);
+    assert_eq!(table.len(), 3);
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let table = table.share();
+            thread::spawn(move || table.call_all(i))
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(handle.join().unwrap(), [i + 1, i * 10, i]);
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn collected_from_iterator() {
+    let table: SharedFnTable<String, usize> = (0..3)
+        .map(|n| {
+            Box::new(move |s: String| s.len() + n) as Box<dyn Fn(String) -> usize + Send + Sync>
+        })
+        .collect();
+    assert_eq!(table.call_all(String::from("ab")), [2, 3, 4]);
+    assert!(SharedFnTable::<(), ()>::default().call_all(()).is_empty());
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "futures")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use semtraits::{Share, ShareFuture};
+use tokio::sync::oneshot;
+
+#[tokio::test]
+async fn shared_future_awaited_from_two_tasks() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = oneshot::channel();
+    let counted = runs.share();
+    let config = async move {
+        counted.fetch_add(1, Ordering::SeqCst);
+        Arc::new(rx.await.unwrap())
+    }
+    .share_future();
+
+    let tasks: Vec<_> = (0..2).map(|_| tokio::spawn(config.share())).collect();
+    tx.send(String::from("loaded")).unwrap();
+
+    let mut results = Vec::new();
+    for task in tasks {
+        results.push(task.await.unwrap());
+    }
+    assert_eq!(*results[0], "loaded");
+    // both got a clone of the same output.
+    assert!(Arc::ptr_eq(&results[0], &results[1]));
+    assert!(Arc::ptr_eq(&results[0], &config.await));
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test(start_paused = true)]
+async fn tasks_wake_together_on_a_shared_delay() {
+    use std::time::Duration;
+
+    use semtraits::shared::delay_shared;
+    use tokio::time::Instant;
+
+    let start = Instant::now();
+    let deadline = delay_shared(Duration::from_millis(500));
+    let tasks: Vec<_> = (0..4)
+        .map(|i| {
+            let deadline = deadline.share();
+            tokio::spawn(async move {
+                // tasks that start waiting later still finish at the deadline.
+                tokio::time::sleep(Duration::from_millis(100 * i)).await;
+                deadline.await;
+                Instant::now()
+            })
+        })
+        .collect();
+
+    for task in tasks {
+        assert_eq!(task.await.unwrap() - start, Duration::from_millis(500));
+    }
+    // already elapsed, so awaiting again completes immediately.
+    deadline.await;
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+}
@@ -0,0 +1,102 @@
+//! `get_or_missing` returns present values and panics at the caller
+//! for absent keys.
+
+/// Runs `f`, which should panic for a missing key, checking that the
+/// panic is reported in this file rather than inside the crate.
+#[cfg(any(feature = "std", feature = "slotmap", feature = "generational-arena"))]
+fn assert_missing(f: impl FnOnce() + std::panic::UnwindSafe) {
+    use std::panic;
+    use std::sync::Mutex;
+    // the panic hook is global, so only one test can swap it at a time.
+    static SERIAL: Mutex<()> = Mutex::new(());
+    static FILE: Mutex<Option<String>> = Mutex::new(None);
+
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        *FILE.lock().unwrap() = info.location().map(|l| l.file().to_string());
+    }));
+    let result = panic::catch_unwind(f);
+    panic::set_hook(hook);
+    let payload = result.expect_err("expected a panic");
+    assert_eq!(*payload.downcast::<String>().unwrap(), "key not present");
+    assert_eq!(FILE.lock().unwrap().take().unwrap(), file!());
+}
+
+#[cfg(feature = "std")]
+mod std_maps {
+    use std::collections::{BTreeMap, HashMap};
+
+    use semtraits::GetOr;
+
+    use crate::assert_missing;
+
+    #[test]
+    fn hash_map() {
+        let map = HashMap::from([(String::from("a"), 1)]);
+        assert_eq!(*map.get_or_missing("a"), 1);
+        assert_missing(|| {
+            map.get_or_missing("b");
+        });
+    }
+
+    #[test]
+    fn btree_map() {
+        let map = BTreeMap::from([(1, "one")]);
+        assert_eq!(*map.get_or_missing(&1), "one");
+        assert_missing(|| {
+            map.get_or_missing(&2);
+        });
+    }
+}
+
+#[cfg(feature = "slotmap")]
+mod slotmap {
+    use semtraits::GetOr;
+    use slotmap::{DenseSlotMap, SlotMap};
+
+    use crate::assert_missing;
+
+    #[test]
+    fn removed_key() {
+        let mut map = SlotMap::new();
+        let key = map.insert("value");
+        assert_eq!(*map.get_or_missing(&key), "value");
+        map.remove(key);
+        assert_missing(|| {
+            map.get_or_missing(&key);
+        });
+    }
+
+    #[test]
+    fn dense_removed_key() {
+        let mut map = DenseSlotMap::new();
+        let key = map.insert(5);
+        assert_eq!(*map.get_or_missing(&key), 5);
+        map.remove(key);
+        assert_missing(|| {
+            map.get_or_missing(&key);
+        });
+    }
+}
+
+#[cfg(feature = "generational-arena")]
+mod generational_arena {
+    use generational_arena::Arena;
+    use semtraits::GetOr;
+
+    use crate::assert_missing;
+
+    #[test]
+    fn stale_index() {
+        let mut arena = Arena::new();
+        let index = arena.insert(1);
+        assert_eq!(*arena.get_or_missing(&index), 1);
+        arena.remove(index);
+        // the slot is reused, but the old index is for an older generation.
+        let _new = arena.insert(2);
+        assert_missing(|| {
+            arena.get_or_missing(&index);
+        });
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+use semtraits::{GetOrTryInit, Share};
+
+#[test]
+fn failure_leaves_the_cell_empty() {
+    let cell = OnceLock::<String>::new();
+    assert_eq!(
+        cell.or_try_init(|| Err("unreachable host")),
+        Err("unreachable host")
+    );
+    assert_eq!(cell.get(), None);
+
+    // a later call can retry.
+    let value = cell.or_try_init(|| Ok::<_, &str>(String::from("connected")));
+    assert_eq!(value.map(String::as_str), Ok("connected"));
+}
+
+#[test]
+fn success_is_only_run_once() {
+    let calls = AtomicUsize::new(0);
+    let cell = OnceLock::new();
+    let init = || {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, ()>(5)
+    };
+    assert_eq!(cell.or_try_init(init), Ok(&5));
+    assert_eq!(cell.or_try_init(init), Ok(&5));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn racing_threads_agree_on_one_value() {
+    let cell = Arc::new(OnceLock::new());
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let cell = cell.share();
+            thread::spawn(move || *cell.or_try_init(|| Ok::<_, ()>(i)).unwrap())
+        })
+        .collect();
+    let seen: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert!(seen.iter().all(|&value| value == seen[0]));
+    assert_eq!(cell.get(), Some(&seen[0]));
+}
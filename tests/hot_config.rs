@@ -0,0 +1,41 @@
+#![cfg(all(feature = "arc-swap", feature = "tokio"))]
+
+use std::sync::Arc;
+
+use semtraits::{Share, shared::HotConfig};
+
+#[tokio::test]
+async fn reload_notifies_subscribers() {
+    let config = HotConfig::new(1);
+    let mut first = config.subscribe();
+    let mut second = config.share().subscribe();
+    assert!(!first.has_changed().unwrap());
+
+    config.share().reload(2);
+    first.changed().await.unwrap();
+    second.changed().await.unwrap();
+    assert_eq!(*config.current(), 2);
+
+    // no further changes until the next reload.
+    assert!(!first.has_changed().unwrap());
+    config.reload(3);
+    assert!(first.has_changed().unwrap());
+    assert_eq!(*config.current(), 3);
+}
+
+#[test]
+fn current_is_a_snapshot() {
+    let config = HotConfig::new(String::from("old"));
+    let before = config.current();
+    config.reload(String::from("new"));
+    assert_eq!(*before, "old");
+    assert_eq!(*config.current(), "new");
+    assert!(!Arc::ptr_eq(&before, &config.current()));
+}
+
+#[test]
+fn reload_without_subscribers() {
+    let config = HotConfig::<u8>::default();
+    config.reload(5);
+    assert_eq!(*config.current(), 5);
+}
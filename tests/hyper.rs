@@ -0,0 +1,17 @@
+#![cfg(feature = "hyper")]
+
+mod common;
+
+use hyper_util::client::legacy::{Client, connect::HttpConnector};
+use hyper_util::rt::TokioExecutor;
+use semtraits::Share;
+
+use common::assert_share;
+
+#[test]
+fn legacy_client_is_share() {
+    assert_share::<Client<HttpConnector, String>>();
+
+    let client = Client::builder(TokioExecutor::new()).build_http::<String>();
+    let _shared: Client<HttpConnector, String> = client.share();
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "left-right")]
+
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use left_right::Absorb;
+use semtraits::{Share, Snapshot};
+
+#[derive(Clone, Default)]
+struct Values(Vec<i32>);
+
+impl Absorb<i32> for Values {
+    fn absorb_first(&mut self, value: &mut i32, _: &Self) {
+        self.0.push(*value);
+    }
+
+    fn sync_with(&mut self, first: &Self) {
+        self.0.clone_from(&first.0);
+    }
+}
+
+#[test]
+fn shared_read_handles_see_published_writes() {
+    let (mut write, read) = left_right::new::<Values, i32>();
+    let other = read.share();
+    write.append(1);
+    // nothing is visible until it is published.
+    assert_eq!(other.snapshot().unwrap().0, []);
+
+    write.publish();
+    assert_eq!(read.snapshot().unwrap().0, [1]);
+    assert_eq!(other.snapshot().unwrap().0, [1]);
+}
+
+#[test]
+fn snapshot_outlives_later_publishes() {
+    let (mut write, read) = left_right::new::<Values, i32>();
+    write.append(1).publish();
+    let before = read.snapshot().unwrap();
+    write.append(2).publish();
+    // the guard keeps reading the copy it started with.
+    assert_eq!(before.0, [1]);
+    drop(before);
+    assert_eq!(read.snapshot().unwrap().0, [1, 2]);
+}
+
+#[test]
+fn concurrent_readers_from_a_factory() {
+    let (mut write, read) = left_right::new::<Values, i32>();
+    write.append(7).publish();
+    let factory = read.factory();
+    let barrier = Arc::new(Barrier::new(4));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let factory = factory.share();
+            let barrier = barrier.share();
+            thread::spawn(move || {
+                let read = factory.handle();
+                let guard = read.snapshot().unwrap();
+                // every reader holds a snapshot at the same time.
+                barrier.wait();
+                guard.0.clone()
+            })
+        })
+        .collect();
+    for reader in readers {
+        assert_eq!(reader.join().unwrap(), [7]);
+    }
+
+    drop(write);
+    assert!(read.snapshot().is_none());
+}
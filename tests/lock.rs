@@ -0,0 +1,164 @@
+//! The same generic code, run against every lock backend.
+
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::thread;
+
+use semtraits::lock::{Lock, ReadWriteLock};
+
+const THREADS: usize = 4;
+const INCREMENTS: usize = 100;
+
+fn increment_from_threads<L>(lock: Arc<L>) -> usize
+where
+    L: Lock<Target = usize> + Send + Sync + 'static,
+{
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let lock = Arc::clone(&lock);
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    *lock.lock() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    *lock.lock()
+}
+
+fn read_while_reading<L: ReadWriteLock<Target = Vec<i32>>>(lock: &L) {
+    lock.write().push(1);
+    let first = lock.read();
+    // a second reader doesn't wait for the first.
+    let second = lock.read();
+    assert_eq!(*first, [1]);
+    assert_eq!(*second, [1]);
+}
+
+#[cfg(feature = "std")]
+mod std_locks {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    use crate::{INCREMENTS, THREADS, increment_from_threads, read_while_reading};
+
+    #[test]
+    fn mutex() {
+        let total = increment_from_threads(Arc::new(Mutex::new(0)));
+        assert_eq!(total, THREADS * INCREMENTS);
+    }
+
+    #[test]
+    fn rw_lock() {
+        read_while_reading(&RwLock::new(Vec::new()));
+    }
+
+    #[cfg(not(feature = "abort-on-closed"))]
+    #[test]
+    fn poisoned_mutex_panics() {
+        use std::panic;
+
+        use semtraits::lock::Lock;
+
+        let mutex = Arc::new(Mutex::new(0));
+        let poisoner = Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let _guard = Lock::lock(&*poisoner);
+            panic!("poisoning the lock");
+        })
+        .join();
+        let payload = panic::catch_unwind(|| drop(Lock::lock(&*mutex))).unwrap_err();
+        assert!(
+            payload
+                .downcast_ref::<String>()
+                .unwrap()
+                .starts_with("lock poisoned")
+        );
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+mod parking_lot_locks {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Arc;
+
+    use parking_lot::{Mutex, RwLock};
+    use semtraits::lock::Lock;
+
+    use crate::{INCREMENTS, THREADS, increment_from_threads, read_while_reading};
+
+    #[test]
+    fn mutex() {
+        let total = increment_from_threads(Arc::new(Mutex::new(0)));
+        assert_eq!(total, THREADS * INCREMENTS);
+    }
+
+    #[test]
+    fn rw_lock() {
+        read_while_reading(&RwLock::new(Vec::new()));
+    }
+
+    #[test]
+    fn not_poisoned_by_panics() {
+        let mutex = Mutex::new(0);
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut guard = Lock::lock(&mutex);
+            *guard = 1;
+            panic!("while holding the lock");
+        }));
+        assert_eq!(*Lock::lock(&mutex), 1);
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_locks {
+    use std::sync::Arc;
+
+    use semtraits::lock::{AsyncLock, AsyncReadWriteLock};
+    use tokio::sync::{Mutex, RwLock};
+
+    use crate::{INCREMENTS, THREADS};
+
+    async fn increment_from_tasks<L>(lock: Arc<L>) -> usize
+    where
+        L: AsyncLock<Target = usize> + Send + Sync + 'static,
+    {
+        let tasks: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                tokio::spawn(async move {
+                    for _ in 0..INCREMENTS {
+                        *lock.lock().await += 1;
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        *lock.lock().await
+    }
+
+    async fn read_while_reading<L: AsyncReadWriteLock<Target = Vec<i32>>>(lock: &L) {
+        lock.write().await.push(1);
+        let first = lock.read().await;
+        let second = lock.read().await;
+        assert_eq!(*first, [1]);
+        assert_eq!(*second, [1]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn mutex() {
+        let total = increment_from_tasks(Arc::new(Mutex::new(0))).await;
+        assert_eq!(total, THREADS * INCREMENTS);
+    }
+
+    #[tokio::test]
+    async fn rw_lock() {
+        read_while_reading(&RwLock::new(Vec::new())).await;
+    }
+}
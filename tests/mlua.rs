@@ -0,0 +1,42 @@
+#![cfg(feature = "mlua")]
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use mlua::Lua;
+use semtraits::{OrPoisoned, Share};
+
+#[test]
+fn shared_lua_state() {
+    let lua = Lua::new();
+    let other = lua.share();
+    other.globals().set("answer", 42).unwrap();
+    assert_eq!(lua.globals().get::<i32>("answer").unwrap(), 42);
+}
+
+#[test]
+fn arc_mutex_lua_across_threads() {
+    // a mutex makes running multiple statements atomic with
+    // respect to other threads.
+    let lua = Arc::new(Mutex::new(Lua::new()));
+    lua.lock().or_poisoned().load("count = 0").exec().unwrap();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let lua = lua.share();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    let lua = lua.lock().or_poisoned();
+                    let count: i32 = lua.globals().get("count").unwrap();
+                    lua.globals().set("count", count + 1).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let count: i32 = lua.lock().or_poisoned().globals().get("count").unwrap();
+    assert_eq!(count, 40);
+}
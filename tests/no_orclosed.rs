@@ -0,0 +1,95 @@
+//! Results that intentionally don't get `OrClosed`.
+//!
+//! `OrClosed` may only apply when an error means the channel can never
+//! be used again. Each type below has an error that a live channel can
+//! also return, so calling `or_closed` on it must not compile.
+//!
+//! `implements_or_closed!` relies on an inherent method with an
+//! `OrClosed` bound taking priority over a trait method with the same
+//! name, and being skipped when the bound doesn't hold.
+
+#![cfg(feature = "std")]
+
+use std::marker::PhantomData;
+
+use semtraits::OrClosed;
+
+struct Probe<T>(PhantomData<T>);
+
+trait Fallback {
+    fn implements_or_closed(&self) -> bool {
+        false
+    }
+}
+
+impl<T> Fallback for Probe<T> {}
+
+impl<T: OrClosed> Probe<T> {
+    fn implements_or_closed(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! implements_or_closed {
+    ($ty:ty) => {
+        Probe::<$ty>(PhantomData).implements_or_closed()
+    };
+}
+
+#[test]
+fn probe_detects_or_closed() {
+    assert!(implements_or_closed!(
+        Result<i32, std::sync::mpsc::RecvError>
+    ));
+    assert!(!implements_or_closed!(Result<i32, ()>));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_mpsc() {
+    use std::sync::mpsc::{RecvTimeoutError, TryRecvError, TrySendError};
+
+    // empty, and the sender may still send.
+    assert!(!implements_or_closed!(Result<i32, TryRecvError>));
+    // timed out, and the sender may still send.
+    assert!(!implements_or_closed!(Result<i32, RecvTimeoutError>));
+    // full, and the receiver may still make room.
+    assert!(!implements_or_closed!(Result<(), TrySendError<i32>>));
+}
+
+#[cfg(feature = "crossbeam-channel")]
+#[test]
+fn crossbeam_channel() {
+    use crossbeam_channel::{RecvTimeoutError, TryRecvError, TrySendError};
+
+    assert!(!implements_or_closed!(Result<i32, TryRecvError>));
+    assert!(!implements_or_closed!(Result<i32, RecvTimeoutError>));
+    assert!(!implements_or_closed!(Result<(), TrySendError<i32>>));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_sync() {
+    use tokio::sync::{broadcast, mpsc, oneshot};
+
+    // lagging behind is reported as an error, but later values can
+    // still be received.
+    assert!(!implements_or_closed!(
+        Result<i32, broadcast::error::RecvError>
+    ));
+    assert!(!implements_or_closed!(
+        Result<i32, broadcast::error::TryRecvError>
+    ));
+    // sending with no receivers fails, but a new one can subscribe.
+    assert!(!implements_or_closed!(
+        Result<usize, broadcast::error::SendError<i32>>
+    ));
+    // empty or full, while both sides are still alive.
+    assert!(!implements_or_closed!(Result<i32, mpsc::error::TryRecvError>));
+    assert!(!implements_or_closed!(
+        Result<(), mpsc::error::TrySendError<i32>>
+    ));
+    assert!(!implements_or_closed!(
+        Result<i32, oneshot::error::TryRecvError>
+    ));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "notify")]
+
+use std::fs;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher};
+use semtraits::{OrClosed, Share};
+
+#[test]
+fn watcher_event_through_shared_sender() {
+    let dir = std::env::temp_dir().join(format!("semtraits-notify-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx.share()).unwrap();
+    watcher.watch(&dir, RecursiveMode::NonRecursive).unwrap();
+
+    // other producers can still use the channel alongside the watcher.
+    tx.send(Err(notify::Error::generic("manual"))).unwrap();
+    assert!(rx.recv().or_closed().is_err());
+
+    fs::write(dir.join("file"), "contents").unwrap();
+    let event = rx.recv().or_closed().unwrap();
+    assert!(event.paths.iter().any(|path| path.ends_with("file")));
+
+    drop(watcher);
+    drop(tx);
+    // drain anything still buffered, then the closed channel is reported.
+    while rx.recv_timeout(Duration::from_secs(1)).is_ok() {}
+    assert!(rx.recv().is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,225 @@
+//! Every `or_closed` impl, checked against the same close paths.
+//!
+//! Each backend gets a module of cases, where a case closes one side of
+//! a channel and then calls `or_closed` on the other side. Supporting a
+//! new backend only needs a new module listing its send/recv cases.
+//!
+//! Closed channels abort under `abort-on-closed`, which
+//! tests/abort_on_closed.rs covers instead.
+
+#![cfg(all(feature = "std", not(feature = "abort-on-closed")))]
+
+use std::panic::{self, UnwindSafe};
+
+const SEND: &str = "sending with disconnected receiver";
+const RECV: &str = "receiving with no senders";
+
+/// Runs `f`, which should panic, returning the panic message.
+fn panic_message(f: impl FnOnce() + UnwindSafe) -> String {
+    let payload = panic::catch_unwind(f).expect_err("expected or_closed to panic");
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+macro_rules! matrix {
+    ($($name:ident => $message:expr, $body:block)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(crate::panic_message(|| $body), $message);
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+mod std_mpsc {
+    use std::sync::mpsc;
+
+    use semtraits::OrClosed;
+
+    use crate::{RECV, SEND};
+
+    matrix! {
+        channel_send => SEND, {
+            let (tx, rx) = mpsc::channel();
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        channel_recv => RECV, {
+            let (tx, rx) = mpsc::channel::<i32>();
+            drop(tx);
+            rx.recv().or_closed();
+        }
+        sync_channel_send => SEND, {
+            let (tx, rx) = mpsc::sync_channel(1);
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        sync_channel_recv => RECV, {
+            let (tx, rx) = mpsc::sync_channel::<i32>(1);
+            drop(tx);
+            rx.recv().or_closed();
+        }
+        channel_send_side => SEND, {
+            let (tx, rx) = mpsc::channel();
+            drop(rx);
+            tx.send(1).or_send_closed();
+        }
+        channel_recv_side => RECV, {
+            let (tx, rx) = mpsc::channel::<i32>();
+            drop(tx);
+            rx.recv().or_recv_closed();
+        }
+    }
+}
+
+#[cfg(feature = "async-channel")]
+mod async_channel {
+    use async_channel::{bounded, unbounded};
+    use semtraits::OrClosed;
+
+    use crate::{RECV, SEND};
+
+    matrix! {
+        bounded_send => SEND, {
+            let (tx, rx) = bounded(1);
+            drop(rx);
+            tx.send_blocking(1).or_closed();
+        }
+        bounded_recv => RECV, {
+            let (tx, rx) = bounded::<i32>(1);
+            drop(tx);
+            rx.recv_blocking().or_closed();
+        }
+        unbounded_send => SEND, {
+            let (tx, rx) = unbounded();
+            drop(rx);
+            tx.send_blocking(1).or_closed();
+        }
+        closed_by_receiver => SEND, {
+            // closing from either side closes the whole channel.
+            let (tx, rx) = unbounded::<i32>();
+            rx.close();
+            tx.send_blocking(1).or_closed();
+        }
+    }
+}
+
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel {
+    use crossbeam_channel::{bounded, unbounded};
+    use semtraits::OrClosed;
+
+    use crate::{RECV, SEND};
+
+    matrix! {
+        bounded_send => SEND, {
+            let (tx, rx) = bounded(1);
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        bounded_recv => RECV, {
+            let (tx, rx) = bounded::<i32>(1);
+            drop(tx);
+            rx.recv().or_closed();
+        }
+        unbounded_send => SEND, {
+            let (tx, rx) = unbounded();
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        unbounded_recv => RECV, {
+            let (tx, rx) = unbounded::<i32>();
+            drop(tx);
+            rx.recv().or_closed();
+        }
+        zero_capacity_send => SEND, {
+            let (tx, rx) = bounded(0);
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+    }
+}
+
+#[cfg(feature = "flume")]
+mod flume {
+    use flume::{bounded, unbounded};
+    use futures::executor::block_on;
+    use semtraits::OrClosed;
+
+    use crate::{RECV, SEND};
+
+    matrix! {
+        bounded_send => SEND, {
+            let (tx, rx) = bounded(1);
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        bounded_recv => RECV, {
+            let (tx, rx) = bounded::<i32>(1);
+            drop(tx);
+            rx.recv().or_closed();
+        }
+        send_async => SEND, {
+            let (tx, rx) = unbounded();
+            drop(rx);
+            block_on(tx.send_async(1)).or_closed();
+        }
+        recv_async => RECV, {
+            let (tx, rx) = unbounded::<i32>();
+            drop(tx);
+            block_on(rx.recv_async()).or_closed();
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_sync {
+    use semtraits::OrClosed;
+    use tokio::sync::{mpsc, oneshot, watch};
+
+    use crate::{RECV, SEND};
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    matrix! {
+        mpsc_send => SEND, {
+            let (tx, rx) = mpsc::channel(1);
+            drop(rx);
+            tx.blocking_send(1).or_closed();
+        }
+        mpsc_unbounded_send => SEND, {
+            let (tx, rx) = mpsc::unbounded_channel();
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        mpsc_reserve => SEND, {
+            let (tx, rx) = mpsc::channel::<i32>(1);
+            drop(rx);
+            block_on(tx.reserve()).or_closed();
+        }
+        watch_send => SEND, {
+            let (tx, rx) = watch::channel(0);
+            drop(rx);
+            tx.send(1).or_closed();
+        }
+        watch_recv => RECV, {
+            let (tx, mut rx) = watch::channel(0);
+            drop(tx);
+            block_on(rx.changed()).or_closed();
+        }
+        oneshot_recv => RECV, {
+            let (tx, rx) = oneshot::channel::<i32>();
+            drop(tx);
+            rx.blocking_recv().or_closed();
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use semtraits::{OrFull, SendOrDrop, Share};
+
+#[test]
+fn shared_sync_sender_fills_up() {
+    let (tx, rx) = mpsc::sync_channel(1);
+    let tx1 = tx.share();
+    thread::spawn(move || tx1.try_send(1).or_full())
+        .join()
+        .unwrap();
+
+    // the other share sees the same full queue.
+    let payload = panic::catch_unwind(|| tx.try_send(2).or_full()).unwrap_err();
+    assert_eq!(*payload.downcast::<String>().unwrap(), "channel full");
+
+    // a blocking send waits for the receiver to make space.
+    let blocked = thread::spawn(move || tx.send(3).unwrap());
+    thread::sleep(Duration::from_millis(50));
+    assert!(!blocked.is_finished());
+    assert_eq!(rx.recv(), Ok(1));
+    blocked.join().unwrap();
+    assert_eq!(rx.recv(), Ok(3));
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[test]
+#[should_panic(expected = "sending with disconnected receiver")]
+fn closed_is_not_full() {
+    let (tx, rx) = mpsc::sync_channel(1);
+    drop(rx);
+    tx.try_send(1).or_full();
+}
+
+struct Counted(Arc<AtomicUsize>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn try_send_or_drop_instead_of_full() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::sync_channel(1);
+    assert!(tx.try_send_or_drop(Counted(dropped.share())));
+    let tx1 = tx.share();
+    assert!(
+        !thread::spawn(move || tx1.try_send_or_drop(Counted(Arc::clone(&dropped))))
+            .join()
+            .unwrap()
+    );
+
+    let sent = rx.recv().unwrap();
+    assert_eq!(sent.0.load(Ordering::SeqCst), 1);
+    drop(rx);
+    // closed channels drop the value too.
+    assert!(!tx.try_send_or_drop(Counted(sent.0.share())));
+    assert_eq!(sent.0.load(Ordering::SeqCst), 2);
+}
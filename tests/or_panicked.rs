@@ -0,0 +1,82 @@
+//! `or_panicked` continues the child's panic with its original payload.
+
+#![cfg(feature = "std")]
+
+use std::any::Any;
+use std::panic::{self, UnwindSafe};
+
+fn payload_of(f: impl FnOnce() + UnwindSafe) -> Box<dyn Any + Send> {
+    panic::catch_unwind(f).expect_err("expected the panic to propagate")
+}
+
+#[cfg(feature = "std")]
+mod std_thread {
+    use std::{panic, thread};
+
+    use semtraits::OrPanicked;
+
+    use crate::payload_of;
+
+    #[test]
+    fn value_when_not_panicked() {
+        assert_eq!(thread::spawn(|| 5).join().or_panicked(), 5);
+    }
+
+    #[test]
+    fn original_payload_is_resumed() {
+        struct Failure(u32);
+
+        let payload = payload_of(|| {
+            thread::spawn(|| panic::panic_any(Failure(7)))
+                .join()
+                .or_panicked();
+        });
+        // not a message, so it can only be the same payload.
+        assert_eq!(payload.downcast::<Failure>().unwrap().0, 7);
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_task {
+    use semtraits::OrPanicked;
+
+    use crate::payload_of;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn value_when_not_panicked() {
+        assert_eq!(
+            block_on(async { tokio::spawn(async { 5 }).await.or_panicked() }),
+            5
+        );
+    }
+
+    #[test]
+    fn original_payload_is_resumed() {
+        let payload = payload_of(|| {
+            block_on(async {
+                let id = std::hint::black_box(3);
+                tokio::spawn(async move { panic!("task {id} failed") })
+                    .await
+                    .or_panicked();
+            })
+        });
+        assert_eq!(*payload.downcast::<String>().unwrap(), "task 3 failed");
+    }
+
+    #[test]
+    #[should_panic(expected = "cancelled")]
+    fn cancelled_task_panics() {
+        block_on(async {
+            let task = tokio::spawn(std::future::pending::<()>());
+            task.abort();
+            task.await.or_panicked();
+        });
+    }
+}
@@ -0,0 +1,89 @@
+//! `or_timeout` panics on a timeout, and the timed out receives also
+//! panic once the channel is closed.
+
+#[cfg(feature = "std")]
+mod std_mpsc {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use semtraits::OrTimeout;
+
+    #[test]
+    fn value_within_timeout() {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || tx.send(1).unwrap());
+        assert_eq!(rx.recv_timeout(Duration::from_secs(10)).or_timeout(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation timed out")]
+    fn timed_out() {
+        let (_tx, rx) = mpsc::channel::<i32>();
+        rx.recv_timeout(Duration::from_millis(10)).or_timeout();
+    }
+
+    #[cfg(not(feature = "abort-on-closed"))]
+    #[test]
+    #[should_panic(expected = "receiving with no senders")]
+    fn disconnected() {
+        let (tx, rx) = mpsc::channel::<i32>();
+        drop(tx);
+        rx.recv_timeout(Duration::from_secs(10)).or_timeout();
+    }
+}
+
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel {
+    use std::time::Duration;
+
+    use crossbeam_channel::bounded;
+    use semtraits::OrTimeout;
+
+    #[test]
+    #[should_panic(expected = "operation timed out")]
+    fn timed_out() {
+        let (_tx, rx) = bounded::<i32>(1);
+        rx.recv_timeout(Duration::from_millis(10)).or_timeout();
+    }
+
+    #[cfg(not(feature = "abort-on-closed"))]
+    #[test]
+    #[should_panic(expected = "receiving with no senders")]
+    fn disconnected() {
+        let (tx, rx) = bounded::<i32>(1);
+        drop(tx);
+        rx.recv_timeout(Duration::from_secs(10)).or_timeout();
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_time {
+    use std::time::Duration;
+
+    use semtraits::OrTimeout;
+    use tokio::time;
+
+    #[tokio::test(start_paused = true)]
+    async fn fast_future_completes() {
+        let fast = async {
+            time::sleep(Duration::from_millis(10)).await;
+            "done"
+        };
+        assert_eq!(
+            time::timeout(Duration::from_secs(1), fast)
+                .await
+                .or_timeout(),
+            "done"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[should_panic(expected = "operation timed out")]
+    async fn slow_future_times_out() {
+        let slow = time::sleep(Duration::from_secs(10));
+        time::timeout(Duration::from_secs(1), slow)
+            .await
+            .or_timeout();
+    }
+}
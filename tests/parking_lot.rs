@@ -0,0 +1,42 @@
+#![cfg(feature = "parking_lot")]
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use semtraits::{LockArc, Share};
+
+#[test]
+fn arc_guard_moves_into_local_task() {
+    let state = Arc::new(Mutex::new(Vec::new()));
+    let mut guard = LockArc::lock_arc(&state.share());
+    guard.push(1);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let local = tokio::task::LocalSet::new();
+    // guards can't be sent to another thread, but a local task only
+    // needs to be `'static`.
+    local.block_on(&runtime, async move {
+        tokio::task::spawn_local(async move {
+            tokio::task::yield_now().await;
+            guard.push(2);
+        })
+        .await
+        .unwrap();
+    });
+    assert_eq!(*state.lock(), [1, 2]);
+    // the guard was the only thing keeping its share alive.
+    assert_eq!(Arc::strong_count(&state), 1);
+}
+
+#[test]
+fn arc_guard_outlives_other_handles() {
+    let guard = LockArc::lock_arc(&Arc::new(Mutex::new(String::from("kept"))));
+    // the arc was a temporary, only the guard holds the mutex now.
+    assert_eq!(*guard, "kept");
+    let mutex = parking_lot::ArcMutexGuard::mutex(&guard).share();
+    assert!(mutex.try_lock().is_none());
+    drop(guard);
+    assert!(mutex.try_lock().is_some());
+}
@@ -0,0 +1,85 @@
+//! Channel senders already share their channel when cloned, so wrapping
+//! them in an [`Arc`] only adds another layer of reference counting.
+//!
+//! These tests show that both approaches reach the same channel, and that
+//! the `Arc` is needed for neither.
+
+#![cfg(feature = "std")]
+
+use std::sync::{Arc, mpsc};
+use std::thread;
+
+use semtraits::{OrClosed, Share};
+
+#[test]
+fn std_sender_share() {
+    let (tx, rx) = mpsc::channel();
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            // preferred: the sender is already a shared handle.
+            let tx = tx.share();
+            thread::spawn(move || tx.send(i).or_closed())
+        })
+        .collect();
+    drop(tx);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut received: Vec<_> = rx.iter().collect();
+    received.sort();
+    assert_eq!(received, [0, 1, 2, 3]);
+}
+
+#[test]
+fn std_sender_arc() {
+    let (tx, rx) = mpsc::channel();
+    // works, but is unnecessary.
+    let tx = Arc::new(tx);
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let tx = tx.share();
+            thread::spawn(move || tx.send(i).or_closed())
+        })
+        .collect();
+    drop(tx);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut received: Vec<_> = rx.iter().collect();
+    received.sort();
+    assert_eq!(received, [0, 1, 2, 3]);
+}
+
+#[test]
+fn std_sender_share_keeps_channel_open() {
+    let (tx, rx) = mpsc::channel();
+    let tx1 = tx.share();
+    drop(tx);
+    // the shared sender alone keeps the channel open, no `Arc` required.
+    tx1.send(10).or_closed();
+    assert_eq!(rx.recv().or_closed(), 10);
+    drop(tx1);
+    assert!(rx.recv().is_err());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_sender_share() {
+    use tokio::sync::mpsc;
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let shared = tx.share();
+    let arced = Arc::new(tx.share());
+    drop(tx);
+
+    shared.send(1).or_closed();
+    arced.send(2).or_closed();
+    drop(shared);
+    drop(arced);
+
+    assert_eq!(rx.blocking_recv(), Some(1));
+    assert_eq!(rx.blocking_recv(), Some(2));
+    assert_eq!(rx.blocking_recv(), None);
+}
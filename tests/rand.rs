@@ -0,0 +1,37 @@
+#![cfg(feature = "rand")]
+
+use std::collections::HashSet;
+use std::thread;
+
+use semtraits::{Share, shared::SharedRng};
+
+fn sequence(seed: u64, len: usize) -> Vec<u64> {
+    let rng = SharedRng::seed_from_u64(seed);
+    (0..len).map(|_| rng.next_u64()).collect()
+}
+
+#[test]
+fn seeded_is_reproducible() {
+    assert_eq!(sequence(7, 32), sequence(7, 32));
+    assert_ne!(sequence(7, 32), sequence(8, 32));
+}
+
+#[test]
+fn threads_draw_from_one_sequence() {
+    let rng = SharedRng::seed_from_u64(7);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let rng = rng.share();
+            thread::spawn(move || (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>())
+        })
+        .collect();
+    let drawn: HashSet<_> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    // the threads interleave differently each run, but between them
+    // they take exactly the first 32 numbers of the seeded sequence.
+    let expected: HashSet<_> = sequence(7, 32).into_iter().collect();
+    assert_eq!(drawn, expected);
+}
@@ -0,0 +1,146 @@
+//! `recv_or` falls back once the senders are gone, and otherwise passes
+//! the received value through.
+
+#[cfg(feature = "std")]
+mod std_mpsc {
+    use std::sync::mpsc;
+    use std::thread;
+
+    use semtraits::RecvOr;
+
+    #[test]
+    fn value_when_sent() {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || tx.send(1).unwrap());
+        assert_eq!(rx.recv().recv_or(|| 0), 1);
+    }
+
+    #[test]
+    fn fallback_after_sender_drops() {
+        let (tx, rx) = mpsc::sync_channel::<i32>(1);
+        drop(tx);
+        assert_eq!(rx.recv().recv_or(|| -1), -1);
+    }
+
+    #[test]
+    fn fallback_is_lazy() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(1).unwrap();
+        assert_eq!(rx.recv().recv_or(|| unreachable!()), 1);
+    }
+}
+
+#[cfg(feature = "async-channel")]
+mod async_channel {
+    use semtraits::RecvOr;
+
+    #[test]
+    fn value_when_sent() {
+        let (tx, rx) = async_channel::unbounded();
+        tx.send_blocking(1).unwrap();
+        assert_eq!(rx.recv_blocking().recv_or(|| unreachable!()), 1);
+    }
+
+    #[test]
+    fn buffered_values_outlast_close() {
+        let (tx, rx) = async_channel::bounded(2);
+        tx.send_blocking(1).unwrap();
+        tx.send_blocking(2).unwrap();
+        // closing from the receiving end still drains what was sent.
+        rx.close();
+        assert_eq!(rx.recv_blocking().recv_or(|| 0), 1);
+        assert_eq!(rx.recv_blocking().recv_or(|| 0), 2);
+        assert_eq!(rx.recv_blocking().recv_or(|| 0), 0);
+    }
+
+    #[test]
+    fn fallback_after_sender_drops() {
+        let (tx, rx) = async_channel::bounded::<i32>(1);
+        drop(tx);
+        assert_eq!(rx.recv_blocking().recv_or(|| -1), -1);
+    }
+}
+
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel {
+    use std::thread;
+    use std::time::Duration;
+
+    use crossbeam_channel::{bounded, unbounded};
+    use semtraits::{RecvOr, RecvTimeoutOr, Share};
+
+    #[test]
+    fn fallback_after_every_sender_drops() {
+        let (tx, rx) = unbounded();
+        let tx1 = tx.share();
+        tx.send(1).unwrap();
+        drop(tx);
+        assert_eq!(rx.recv().recv_or(|| 0), 1);
+        tx1.send(2).unwrap();
+        drop(tx1);
+        assert_eq!(rx.recv().recv_or(|| 0), 2);
+        assert_eq!(rx.recv().recv_or(|| 0), 0);
+    }
+
+    #[test]
+    fn recv_timeout_or_timed_out() {
+        let (tx, rx) = unbounded::<i32>();
+        let wait = Duration::from_millis(10);
+        assert_eq!(
+            rx.recv_timeout(wait).recv_timeout_or(|timed_out| {
+                assert!(timed_out);
+                -1
+            }),
+            -1
+        );
+
+        // a value that arrives in time is passed through.
+        tx.send(5).unwrap();
+        assert_eq!(rx.recv_timeout(wait).recv_timeout_or(|_| unreachable!()), 5);
+    }
+
+    #[test]
+    fn recv_timeout_or_disconnected() {
+        let (tx, rx) = bounded::<i32>(1);
+        let waiting = thread::spawn(move || {
+            rx.recv_timeout(Duration::from_secs(10))
+                .recv_timeout_or(|timed_out| if timed_out { -1 } else { -2 })
+        });
+        // dropping the sender wakes the receiver before the timeout.
+        drop(tx);
+        assert_eq!(waiting.join().unwrap(), -2);
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_sync {
+    use semtraits::RecvOr;
+    use tokio::sync::{oneshot, watch};
+
+    #[tokio::test]
+    async fn oneshot_value_when_sent() {
+        let (tx, rx) = oneshot::channel();
+        tx.send(1).unwrap();
+        assert_eq!(rx.await.recv_or(|| 0), 1);
+    }
+
+    #[tokio::test]
+    async fn oneshot_fallback_after_sender_drops() {
+        let (tx, rx) = oneshot::channel::<i32>();
+        drop(tx);
+        assert_eq!(rx.await.recv_or(|| -1), -1);
+    }
+
+    #[tokio::test]
+    async fn watch_changed_until_sender_drops() {
+        let (tx, mut rx) = watch::channel(0);
+        let mut seen = Vec::new();
+        tokio::spawn(async move {
+            tx.send(1).unwrap();
+        });
+        while rx.changed().await.map(|()| true).recv_or(|| false) {
+            seen.push(*rx.borrow_and_update());
+        }
+        assert_eq!(seen, [1]);
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use semtraits::{Share, shared::SharedResource};
+
+fn counted(calls: &Arc<AtomicUsize>) -> SharedResource<Vec<i32>> {
+    let calls = calls.share();
+    SharedResource::with_cleanup(vec![1, 2, 3], move |value| {
+        assert_eq!(*value, [1, 2, 3]);
+        calls.fetch_add(1, Ordering::SeqCst);
+    })
+}
+
+#[test]
+fn cleanup_runs_once_after_every_share_drops() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let resource = counted(&calls);
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let resource = resource.share();
+            thread::spawn(move || resource.iter().sum::<i32>())
+        })
+        .collect();
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    drop(resource);
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn cleanup_waits_for_the_last_handle() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let first = counted(&calls);
+    let second = first.share();
+    assert_eq!(second.handle_count(), 2);
+
+    drop(first);
+    assert_eq!(second.handle_count(), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    drop(second);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn no_cleanup_by_default() {
+    let resource = SharedResource::new(5);
+    assert_eq!(*resource.share(), 5);
+}
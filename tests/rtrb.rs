@@ -0,0 +1,42 @@
+#![cfg(feature = "rtrb")]
+
+use std::panic;
+use std::thread;
+
+use rtrb::RingBuffer;
+use semtraits::{Pop, Push};
+
+#[test]
+fn values_cross_threads_in_order() {
+    let (mut producer, mut consumer) = RingBuffer::new(4);
+    let writer = thread::spawn(move || {
+        for i in 0..4 {
+            producer.push_or_full(i);
+        }
+    });
+    writer.join().unwrap();
+    let values: Vec<_> = (0..4).map(|_| consumer.pop_or_empty()).collect();
+    assert_eq!(values, [0, 1, 2, 3]);
+}
+
+#[test]
+fn push_when_full_panics() {
+    let (mut producer, mut consumer) = RingBuffer::new(1);
+    producer.push_or_full("a");
+    let payload =
+        panic::catch_unwind(panic::AssertUnwindSafe(|| producer.push_or_full("b"))).unwrap_err();
+    assert_eq!(*payload.downcast::<String>().unwrap(), "channel full");
+    // the value that did fit is still there.
+    assert_eq!(consumer.pop_or_empty(), "a");
+}
+
+#[test]
+fn pop_when_empty_panics() {
+    let (mut producer, mut consumer) = RingBuffer::<i32>::new(1);
+    let payload =
+        panic::catch_unwind(panic::AssertUnwindSafe(|| consumer.pop_or_empty())).unwrap_err();
+    assert_eq!(*payload.downcast::<String>().unwrap(), "channel empty");
+
+    producer.push_or_full(1);
+    assert_eq!(consumer.pop_or_empty(), 1);
+}
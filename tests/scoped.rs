@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread;
+
+use semtraits::shared::ScopedShared;
+
+#[test]
+fn every_scoped_thread_adds_to_the_total() {
+    let total = ScopedShared::new(Arc::new(AtomicUsize::new(0)));
+    let chunks: Vec<Vec<usize>> = (0..4).map(|i| (i * 10..i * 10 + 10).collect()).collect();
+
+    thread::scope(|scope| {
+        for chunk in &chunks {
+            // each thread borrows its chunk and gets its own handle.
+            total.spawn_with(scope, move |total| {
+                total.fetch_add(chunk.iter().sum(), Ordering::Relaxed);
+            });
+        }
+    });
+
+    let total = total.into_inner();
+    assert_eq!(total.load(Ordering::Relaxed), (0..40).sum());
+    assert_eq!(Arc::strong_count(&total), 1);
+}
+
+#[test]
+fn results_come_back_through_join_handles() {
+    let (tx, rx) = mpsc::channel();
+    let tx = ScopedShared::new(tx);
+    let names = ["a", "b"];
+    let lengths = thread::scope(|scope| {
+        let handles: Vec<_> = names
+            .iter()
+            .map(|name| {
+                tx.spawn_with(scope, move |tx| {
+                    tx.send(*name).unwrap();
+                    name.len()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    drop(tx);
+
+    assert_eq!(lengths, [1, 1]);
+    let mut sent: Vec<_> = rx.iter().collect();
+    sent.sort();
+    assert_eq!(sent, names);
+}
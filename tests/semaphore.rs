@@ -0,0 +1,63 @@
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use semtraits::{Share, shared::SharedSemaphore};
+use tokio::task::JoinSet;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn limits_how_many_tasks_run_at_once() {
+    let limit = SharedSemaphore::new(3);
+    let running = Arc::new(AtomicUsize::new(0));
+    let high_water = Arc::new(AtomicUsize::new(0));
+
+    let mut tasks = JoinSet::new();
+    for _ in 0..12 {
+        let limit = limit.share();
+        let running = running.share();
+        let high_water = high_water.share();
+        tasks.spawn(async move {
+            let _permit = limit.acquire().await;
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            high_water.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            running.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        result.unwrap();
+    }
+
+    assert!(high_water.load(Ordering::SeqCst) <= 3);
+    assert_eq!(limit.available(), 3);
+}
+
+#[tokio::test]
+async fn close_wakes_waiters_with_a_panic() {
+    let limit = SharedSemaphore::new(1);
+    let held = limit.acquire().await;
+    let waiter = limit.share();
+    let waiting = tokio::spawn(async move {
+        let _permit = waiter.acquire().await;
+    });
+    tokio::task::yield_now().await;
+
+    limit.close();
+    let err = waiting.await.unwrap_err();
+    assert_eq!(
+        *err.into_panic().downcast::<String>().unwrap(),
+        "semaphore closed"
+    );
+    // the permit that was already held is still valid.
+    drop(held);
+}
+
+#[tokio::test]
+#[should_panic(expected = "semaphore closed")]
+async fn acquire_after_close_panics() {
+    let limit = SharedSemaphore::new(1);
+    limit.share().close();
+    let _permit = limit.acquire().await;
+}
@@ -0,0 +1,134 @@
+//! `send_or` hands the unsent value to the closure once the receiver is
+//! gone, and leaves successful sends alone.
+
+#[cfg(feature = "std")]
+mod std_mpsc {
+    use std::sync::mpsc;
+
+    use semtraits::SendOr;
+
+    #[test]
+    fn closure_receives_unsent_value() {
+        let (tx, rx) = mpsc::channel();
+        assert_eq!(tx.send(1).send_or(|_| unreachable!()), Ok(()));
+        assert_eq!(rx.recv(), Ok(1));
+
+        drop(rx);
+        let mut unsent = None;
+        assert_eq!(tx.send(2).send_or(|value| unsent = Some(value)), Err(()));
+        assert_eq!(unsent, Some(2));
+    }
+}
+
+#[cfg(feature = "async-channel")]
+mod async_channel {
+    use semtraits::{SendOr, Share};
+
+    #[test]
+    fn closure_receives_unsent_value() {
+        let (tx, rx) = async_channel::bounded(1);
+        assert_eq!(tx.send_blocking(1).send_or(|_| unreachable!()), Ok(()));
+        assert_eq!(rx.recv_blocking(), Ok(1));
+
+        drop(rx);
+        let tx1 = tx.share();
+        let mut unsent = Vec::new();
+        let _ = tx.send_blocking(2).send_or(|value| unsent.push(value));
+        let _ = tx1.send_blocking(3).send_or(|value| unsent.push(value));
+        assert_eq!(unsent, [2, 3]);
+    }
+
+    #[test]
+    fn async_send_after_receiver_drop() {
+        let (tx, rx) = async_channel::unbounded();
+        drop(rx);
+        let mut unsent = None;
+        let result = futures::executor::block_on(tx.send("late")).send_or(|v| unsent = Some(v));
+        assert_eq!(result, Err(()));
+        assert_eq!(unsent, Some("late"));
+    }
+}
+
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel {
+    use std::thread;
+
+    use crossbeam_channel::bounded;
+    use semtraits::{SendOr, Share, TrySendOr};
+
+    #[test]
+    fn closure_receives_unsent_value() {
+        let (tx, rx) = bounded(1);
+        drop(rx);
+        let mut requeued = Vec::new();
+        for i in 0..3 {
+            assert_eq!(tx.send(i).send_or(|value| requeued.push(value)), Err(()));
+        }
+        assert_eq!(requeued, [0, 1, 2]);
+    }
+
+    #[test]
+    fn requeue_to_a_fallback_channel() {
+        let (primary, primary_rx) = bounded(0);
+        let (fallback, fallback_rx) = bounded(4);
+        let worker = thread::spawn(move || primary_rx.recv());
+        let fallback_tx = fallback.share();
+        primary
+            .send("first")
+            .send_or(|msg| fallback_tx.send(msg).unwrap())
+            .unwrap();
+        assert_eq!(worker.join().unwrap(), Ok("first"));
+
+        // the worker has exited and dropped its receiver.
+        let _ = primary
+            .send("second")
+            .send_or(|msg| fallback.send(msg).unwrap());
+        assert_eq!(fallback_rx.try_recv(), Ok("second"));
+    }
+
+    #[test]
+    fn try_send_or_when_full() {
+        let (tx, rx) = bounded(1);
+        assert_eq!(
+            tx.try_send(1)
+                .try_send_or(|_| unreachable!(), |_| unreachable!()),
+            Ok(())
+        );
+        let mut full = None;
+        let result = tx
+            .try_send(2)
+            .try_send_or(|value| full = Some(value), |_| unreachable!());
+        assert_eq!(result, Err(()));
+        assert_eq!(full, Some(2));
+        assert_eq!(rx.try_recv(), Ok(1));
+    }
+
+    #[test]
+    fn try_send_or_when_closed() {
+        // a zero capacity channel is always full, but closing takes
+        // precedence.
+        let (tx, rx) = bounded(0);
+        drop(rx);
+        let mut closed = None;
+        let result = tx
+            .try_send(String::from("a"))
+            .try_send_or(|_| unreachable!(), |value| closed = Some(value));
+        assert_eq!(result, Err(()));
+        assert_eq!(closed.as_deref(), Some("a"));
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_mpsc {
+    use semtraits::SendOr;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn closure_receives_unsent_value() {
+        let (tx, rx) = mpsc::channel(1);
+        drop(rx);
+        let mut unsent = None;
+        assert_eq!(tx.send("late").await.send_or(|v| unsent = Some(v)), Err(()));
+        assert_eq!(unsent, Some("late"));
+    }
+}
@@ -0,0 +1,37 @@
+//! `or_closed` on a failed send should drop the unsent value before
+//! panicking, rather than holding onto it until the unwind finishes.
+
+#![cfg(all(feature = "std", not(feature = "abort-on-closed")))]
+
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+
+use semtraits::OrClosed;
+
+static DROPPED: AtomicBool = AtomicBool::new(false);
+static DROPPED_BEFORE_PANIC: AtomicBool = AtomicBool::new(false);
+
+struct Payload;
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn std_send_drops_payload_before_panic() {
+    panic::set_hook(Box::new(|_| {
+        DROPPED_BEFORE_PANIC.store(DROPPED.load(Ordering::SeqCst), Ordering::SeqCst);
+    }));
+
+    let (tx, rx) = mpsc::channel();
+    drop(rx);
+    let result = panic::catch_unwind(|| tx.send(Payload).or_closed());
+    let _ = panic::take_hook();
+
+    assert!(result.is_err());
+    assert!(DROPPED.load(Ordering::SeqCst));
+    assert!(DROPPED_BEFORE_PANIC.load(Ordering::SeqCst));
+}
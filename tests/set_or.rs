@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+use semtraits::{SetOr, Share};
+
+#[test]
+fn set_once_lock_once() {
+    let cell = OnceLock::new();
+    cell.set_or_already_set(String::from("a"));
+    assert_eq!(cell.get().map(String::as_str), Some("a"));
+}
+
+#[test]
+fn set_once_lock_twice_from_another_thread() {
+    let cell = Arc::new(OnceLock::new());
+    cell.set_or_already_set(1);
+
+    let twice = cell.share();
+    let payload = thread::spawn(move || twice.set_or_already_set(2))
+        .join()
+        .unwrap_err();
+    assert_eq!(
+        *payload.downcast::<String>().unwrap(),
+        "cell already initialized"
+    );
+    // the first value is kept.
+    assert_eq!(cell.get(), Some(&1));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+#[should_panic(expected = "cell already initialized")]
+fn set_tokio_once_cell_twice() {
+    let cell = tokio::sync::OnceCell::new();
+    cell.set_or_already_set(1);
+    cell.set_or_already_set(2);
+}
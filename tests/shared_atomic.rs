@@ -0,0 +1,58 @@
+use semtraits::SharedAtomic;
+use std::sync::atomic::{
+    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+    AtomicU32, AtomicU64, AtomicUsize,
+};
+
+macro_rules! round_trip {
+    ($($name:ident: $atomic:ident($initial:expr, $next:expr, $last:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let atomic = $atomic::new($initial);
+                assert_eq!(atomic.get(), $initial);
+                atomic.set($next);
+                assert_eq!(atomic.get(), $next);
+                assert_eq!(atomic.swap_in($last), $next);
+                assert_eq!(atomic.get(), $last);
+            }
+        )*
+    };
+}
+
+round_trip! {
+    bool: AtomicBool(false, true, false),
+    u8: AtomicU8(0, u8::MAX, 7),
+    i8: AtomicI8(0, i8::MIN, -7),
+    u16: AtomicU16(0, u16::MAX, 7),
+    i16: AtomicI16(0, i16::MIN, -7),
+    u32: AtomicU32(0, u32::MAX, 7),
+    i32: AtomicI32(0, i32::MIN, -7),
+    u64: AtomicU64(0, u64::MAX, 7),
+    i64: AtomicI64(0, i64::MIN, -7),
+    usize: AtomicUsize(0, usize::MAX, 7),
+    isize: AtomicIsize(0, isize::MIN, -7),
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn swap_hands_out_each_value_once() {
+    use std::sync::Arc;
+    use std::thread;
+
+    use semtraits::Share;
+
+    let latest = Arc::new(AtomicU64::new(0));
+    let handles: Vec<_> = (1..=8)
+        .map(|id| {
+            let latest = latest.share();
+            thread::spawn(move || latest.swap_in(id))
+        })
+        .collect();
+    let mut seen: Vec<u64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    seen.push(latest.get());
+    seen.sort();
+    // every stored value is returned by exactly one later swap, or is
+    // still there at the end.
+    assert_eq!(seen, (0..=8).collect::<Vec<_>>());
+}
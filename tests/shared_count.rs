@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+
+use semtraits::{Share, SharedCount};
+
+#[test]
+fn rc_unique_until_shared() {
+    let value = Rc::new(String::from("a"));
+    assert!(value.is_unique());
+    let other = value.share();
+    assert!(!value.is_unique());
+    assert!(!other.is_unique());
+    drop(other);
+    assert!(value.is_unique());
+}
+
+#[test]
+fn weak_pointers_count_too() {
+    let value = Rc::new(1);
+    let weak = Rc::downgrade(&value);
+    // a weak pointer could still be upgraded.
+    assert!(!value.is_unique());
+    drop(weak);
+    assert!(value.is_unique());
+}
+
+#[test]
+fn arc_unique_after_threads_drop_their_shares() {
+    let value = Arc::new(vec![1, 2, 3]);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let value = value.share();
+            thread::spawn(move || value.iter().sum::<i32>())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+    assert!(value.is_unique());
+}
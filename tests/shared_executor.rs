@@ -0,0 +1,52 @@
+#![cfg(feature = "smol")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use semtraits::{Share, shared::SharedExecutor, spawn::Spawn};
+use smol::channel;
+
+#[test]
+fn detached_tasks_from_every_thread_run_to_completion() {
+    let executor = SharedExecutor::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let (done, done_rx) = channel::unbounded();
+
+    let spawners: Vec<_> = (0..4)
+        .map(|_| {
+            let executor = executor.share();
+            let count = count.share();
+            let done = done.share();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    let count = count.share();
+                    let done = done.share();
+                    executor.spawn_detached(async move {
+                        count.fetch_add(1, Ordering::Relaxed);
+                        done.send(()).await.unwrap();
+                    });
+                }
+            })
+        })
+        .collect();
+    for spawner in spawners {
+        spawner.join().unwrap();
+    }
+
+    smol::block_on(executor.run(async {
+        for _ in 0..40 {
+            done_rx.recv().await.unwrap();
+        }
+    }));
+    assert_eq!(count.load(Ordering::Relaxed), 40);
+}
+
+#[test]
+fn spawn_trait_detaches_the_task() {
+    let executor = SharedExecutor::new();
+    let (tx, rx) = channel::bounded(1);
+    // the task's output doesn't have to be `()`.
+    executor.spawn_detached(async move { tx.send(5).await });
+    assert_eq!(smol::block_on(executor.run(rx.recv())), Ok(5));
+}
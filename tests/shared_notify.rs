@@ -0,0 +1,41 @@
+#![cfg(feature = "tokio")]
+
+use semtraits::{Share, shared::SharedNotify};
+use tokio::sync::mpsc;
+use tokio::task;
+
+#[tokio::test]
+async fn wake_all_wakes_every_waiter() {
+    let notify = SharedNotify::new();
+    let (woken, mut woken_rx) = mpsc::unbounded_channel();
+    for i in 0..4 {
+        let notify = notify.share();
+        let woken = woken.share();
+        tokio::spawn(async move {
+            notify.wait().await;
+            woken.send(i).unwrap();
+        });
+    }
+    drop(woken);
+    // let every task start waiting.
+    task::yield_now().await;
+    assert!(woken_rx.try_recv().is_err());
+
+    notify.share().wake_all();
+    let mut order = Vec::new();
+    while let Some(i) = woken_rx.recv().await {
+        order.push(i);
+    }
+    order.sort();
+    assert_eq!(order, [0, 1, 2, 3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn late_waiters_miss_earlier_wakeups() {
+    use std::time::Duration;
+
+    let notify = SharedNotify::new();
+    notify.wake_all();
+    let missed = tokio::time::timeout(Duration::from_secs(1), notify.wait()).await;
+    assert!(missed.is_err());
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "futures")]
+
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt, stream};
+use semtraits::{Share, ShareStream};
+
+#[tokio::test]
+async fn every_clone_receives_every_item() {
+    let (mut tx, rx) = mpsc::channel(1);
+    let shared = rx.share_stream();
+    let consumers: Vec<_> = (0..3)
+        .map(|_| tokio::spawn(shared.share().collect::<Vec<i32>>()))
+        .collect();
+    drop(shared);
+
+    for i in 0..10 {
+        tx.send(i).await.unwrap();
+    }
+    drop(tx);
+
+    for consumer in consumers {
+        assert_eq!(consumer.await.unwrap(), (0..10).collect::<Vec<_>>());
+    }
+}
+
+#[tokio::test]
+async fn dropped_clone_does_not_stall_the_others() {
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut first = rx.share_stream();
+    let mut second = first.share();
+
+    let waiting = tokio::spawn(async move { second.next().await });
+    // let `second` register as waiting before `first` polls and drops.
+    tokio::task::yield_now().await;
+    assert!(futures::poll!(first.next()).is_pending());
+    drop(first);
+
+    tx.send(1).await.unwrap();
+    assert_eq!(waiting.await.unwrap(), Some(1));
+}
+
+#[test]
+fn slow_clone_sees_buffered_items() {
+    let mut fast = stream::iter(["a", "b", "c"]).share_stream();
+    let slow = fast.share();
+    let fast_items: Vec<_> = futures::executor::block_on((&mut fast).collect());
+    assert_eq!(fast_items, ["a", "b", "c"]);
+    assert_eq!(futures::executor::block_on(fast.next()), None);
+    assert_eq!(
+        futures::executor::block_on(slow.collect::<Vec<_>>()),
+        ["a", "b", "c"]
+    );
+}
@@ -0,0 +1,96 @@
+//! Spawned tasks run to completion without keeping a handle to them.
+
+#[cfg(feature = "std")]
+mod std_threads {
+    use std::sync::{Arc, Barrier, mpsc};
+
+    use semtraits::spawn::{Spawn, StdThreads};
+
+    #[test]
+    fn spawned_thread_runs() {
+        let (tx, rx) = mpsc::channel();
+        StdThreads.spawn_detached(move || tx.send(thread_id()).unwrap());
+        assert_ne!(rx.recv().unwrap(), thread_id());
+    }
+
+    #[test]
+    fn spawn_does_not_wait() {
+        let barrier = Arc::new(Barrier::new(2));
+        let (tx, rx) = mpsc::channel();
+        let task_barrier = Arc::clone(&barrier);
+        StdThreads.spawn_detached(move || {
+            // only passes once the spawner has carried on.
+            task_barrier.wait();
+            tx.send(()).unwrap();
+        });
+        barrier.wait();
+        rx.recv().unwrap();
+    }
+
+    fn thread_id() -> std::thread::ThreadId {
+        std::thread::current().id()
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_runtime {
+    use semtraits::spawn::Spawn;
+    use tokio::runtime::{Builder, Handle};
+    use tokio::sync::oneshot;
+
+    #[test]
+    fn runtime_spawn_detached() {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .build()
+            .unwrap();
+        let (tx, rx) = oneshot::channel();
+        runtime.spawn_detached(async move { tx.send(1).unwrap() });
+        assert_eq!(rx.blocking_recv().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_spawn_detached() {
+        let (tx, rx) = oneshot::channel();
+        Handle::current().spawn_detached(async move { tx.send(2).unwrap() });
+        assert_eq!(rx.await.unwrap(), 2);
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_pool {
+    use std::sync::mpsc;
+
+    use rayon::ThreadPoolBuilder;
+    use semtraits::spawn::Spawn;
+
+    #[test]
+    fn pool_spawn_detached() {
+        let pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        let (tx, rx) = mpsc::channel();
+        for i in 0..4 {
+            let tx = tx.clone();
+            pool.spawn_detached(move || tx.send(i).unwrap());
+        }
+        drop(tx);
+        let mut received: Vec<_> = rx.iter().collect();
+        received.sort();
+        assert_eq!(received, [0, 1, 2, 3]);
+    }
+}
+
+#[cfg(feature = "smol")]
+mod smol_executor {
+    use semtraits::spawn::Spawn;
+    use smol::{Executor, channel};
+
+    #[test]
+    fn executor_task_outlives_its_handle() {
+        let executor = Executor::new();
+        let (tx, rx) = channel::bounded(1);
+        // the task handle is dropped here; the task must not be cancelled.
+        executor.spawn_detached(async move { tx.send(3).await.unwrap() });
+        let value = smol::block_on(executor.run(rx.recv()));
+        assert_eq!(value.unwrap(), 3);
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::thread;
+
+use semtraits::SplitClone;
+
+#[test]
+fn split_loopback_stream() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // the peer echoes each line back.
+    let peer = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let (read, mut write) = stream.split_clone().unwrap();
+        for line in BufReader::new(read).lines() {
+            writeln!(write, "{}", line.unwrap()).unwrap();
+        }
+    });
+
+    let stream = TcpStream::connect(addr).unwrap();
+    let (read, mut write) = stream.split_clone().unwrap();
+    assert_eq!(read.peer_addr().unwrap(), write.peer_addr().unwrap());
+
+    writeln!(write, "one").unwrap();
+    writeln!(write, "two").unwrap();
+    let mut lines = BufReader::new(read).lines();
+    assert_eq!(lines.next().unwrap().unwrap(), "one");
+    assert_eq!(lines.next().unwrap().unwrap(), "two");
+
+    // shutting down one handle ends the stream for the peer, even
+    // though the other handles are still open.
+    write.shutdown(Shutdown::Write).unwrap();
+    peer.join().unwrap();
+    assert!(lines.next().is_none());
+}
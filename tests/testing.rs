@@ -0,0 +1,28 @@
+#![cfg(all(feature = "std", feature = "testing"))]
+
+use std::rc::Rc;
+use std::sync::{Arc, mpsc};
+
+use semtraits::testing::assert_shares;
+
+#[test]
+fn reference_counted_pointers_share() {
+    assert_shares(&Arc::new(String::from("a")), Arc::ptr_eq);
+    assert_shares(&Rc::new(vec![1, 2]), Rc::ptr_eq);
+}
+
+#[test]
+fn senders_share_their_channel() {
+    let (tx, rx) = mpsc::channel();
+    assert_shares(&tx, |a, b| {
+        a.send(1).unwrap();
+        b.send(2).unwrap();
+        rx.try_iter().collect::<Vec<_>>() == [1, 2]
+    });
+}
+
+#[test]
+#[should_panic(expected = "doesn't share state")]
+fn distinct_allocations_fail() {
+    assert_shares(&Arc::new(1), |a, b| Arc::ptr_eq(a, &Arc::new(**b)));
+}
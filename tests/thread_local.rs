@@ -0,0 +1,50 @@
+#![cfg(all(feature = "thread-local", feature = "std"))]
+
+mod common;
+
+use std::sync::{Arc, Barrier, mpsc};
+use std::thread;
+
+use semtraits::{OrClosed, PerThread, Share};
+use thread_local::ThreadLocal;
+
+use common::assert_share;
+
+#[test]
+fn arc_thread_local_is_share() {
+    assert_share::<Arc<ThreadLocal<u32>>>();
+}
+
+#[test]
+fn per_thread_values_through_shared_handle() {
+    let local = Arc::new(ThreadLocal::new());
+    let (tx, rx) = mpsc::channel();
+    // keep every thread alive at once, exited threads have their
+    // slots reused by new threads.
+    let barrier = Arc::new(Barrier::new(4));
+    assert_eq!(*local.per_thread(|| 10), 10);
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let local = local.share();
+            let tx = tx.share();
+            let barrier = barrier.share();
+            thread::spawn(move || {
+                // only the first call on each thread initializes.
+                let first = *local.per_thread(|| i);
+                let second = *local.per_thread(|| i + 100);
+                tx.send((first, second)).or_closed();
+                barrier.wait();
+            })
+        })
+        .collect();
+    drop(tx);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut received: Vec<_> = rx.iter().collect();
+    received.sort();
+    assert_eq!(received, [(0, 0), (1, 1), (2, 2), (3, 3)]);
+    assert_eq!(*local.per_thread(|| 20), 10);
+}
@@ -0,0 +1,709 @@
+#![cfg(feature = "tokio")]
+
+use semtraits::{
+    AsyncGetOrTryInit, AwaitClosed, BlockOn, BlockingLock, BroadcastSend, ChannelMetrics, Drain,
+    IntoUnsent, JoinAll, LockOwned, OrAlreadySet, OrClosed, OrPoisoned, ReadWriteOwned, RecvLatest,
+    RecvMany, RecvSkipLagged, Reserve, SendIfChanged, SendOrDrop, Share, Split, Tick, TryReadWrite,
+};
+use tokio::sync::{RwLock, broadcast, mpsc, watch};
+use tokio::task;
+
+fn panic_message(err: task::JoinError) -> String {
+    let payload = err.into_panic();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+async fn blocking_send_from_spawn_blocking() {
+    let (tx, mut rx) = mpsc::channel(1);
+    let tx1 = tx.clone();
+    task::spawn_blocking(move || tx1.blocking_send(10).or_closed())
+        .await
+        .unwrap();
+    assert_eq!(rx.recv().await, Some(10));
+
+    drop(rx);
+    let err = task::spawn_blocking(move || tx.blocking_send(20).or_closed())
+        .await
+        .unwrap_err();
+    assert!(err.is_panic());
+    assert_eq!(panic_message(err), "sending with disconnected receiver");
+}
+
+#[tokio::test]
+async fn reserve_many_permits() {
+    let (tx, mut rx) = mpsc::channel(4);
+    for (i, permit) in tx.reserve_many(3).await.or_closed().enumerate() {
+        permit.send(i);
+    }
+    for i in 0..3 {
+        assert_eq!(rx.recv().await, Some(i));
+    }
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+#[should_panic(expected = "sending with disconnected receiver")]
+async fn reserve_many_after_receiver_drop() {
+    let (tx, rx) = mpsc::channel::<i32>(4);
+    drop(rx);
+    let _ = tx.reserve_many(2).await.or_closed();
+}
+
+#[tokio::test]
+async fn shared_unbounded_sender_keeps_channel_alive() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let tx1 = tx.share();
+    drop(tx);
+
+    task::spawn(async move {
+        tx1.send(1).or_closed();
+        tx1.send(2).or_closed();
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.recv().await, Some(2));
+    // the only remaining share was dropped at the end of the task.
+    assert_eq!(rx.recv().await, None);
+}
+
+#[tokio::test]
+async fn into_unsent_when_full() {
+    let (tx, mut rx) = mpsc::channel(1);
+    assert_eq!(tx.try_send(String::from("a")).into_unsent(), Ok(()));
+    assert_eq!(
+        tx.try_send(String::from("b")).into_unsent(),
+        Err(String::from("b"))
+    );
+    assert_eq!(rx.recv().await.as_deref(), Some("a"));
+}
+
+#[tokio::test]
+async fn into_unsent_when_closed() {
+    let (tx, rx) = mpsc::channel(1);
+    drop(rx);
+    assert_eq!(
+        tx.try_send(String::from("a")).into_unsent(),
+        Err(String::from("a"))
+    );
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+async fn watch_send_after_all_receivers_dropped() {
+    let (tx, mut rx) = watch::channel(0);
+    let rx1 = tx.subscribe();
+    tx.send(1).or_closed();
+    rx.changed().await.or_closed();
+    assert_eq!(*rx.borrow_and_update(), 1);
+
+    drop(rx);
+    // one receiver is still listening.
+    tx.send(2).or_closed();
+    drop(rx1);
+
+    let err = task::spawn(async move { tx.send(3).or_closed() })
+        .await
+        .unwrap_err();
+    assert_eq!(panic_message(err), "sending with disconnected receiver");
+}
+
+#[tokio::test]
+async fn try_read_contended_by_write_guard() {
+    let lock = std::sync::Arc::new(RwLock::new(0));
+    let guard = lock.share().write_owned().await;
+
+    let contended = lock.share();
+    let err = task::spawn(async move {
+        let _ = contended.try_read_or_contended();
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(panic_message(err), "lock contended");
+
+    drop(guard);
+    assert_eq!(*lock.try_read_or_contended(), 0);
+}
+
+#[tokio::test]
+async fn try_write_contended_by_read_guard() {
+    let lock = std::sync::Arc::new(RwLock::new(0));
+    let guard = lock.share().read_owned().await;
+    // other readers are fine.
+    assert_eq!(*lock.try_read_or_contended(), 0);
+
+    let contended = lock.share();
+    let err = task::spawn(async move {
+        let _ = contended.try_write_or_contended();
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(panic_message(err), "lock contended");
+
+    drop(guard);
+    *lock.try_write_or_contended() += 1;
+    assert_eq!(*lock.read().await, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn ticks_fire_as_time_advances() {
+    use std::time::Duration;
+    use tokio::time::{self, Instant};
+
+    let period = Duration::from_millis(100);
+    let start = Instant::now();
+    let mut interval = time::interval(period);
+    let mut ticks = Vec::new();
+    for _ in 0..4 {
+        ticks.push(interval.tick_now().await - start);
+    }
+    assert_eq!(ticks, [Duration::ZERO, period, period * 2, period * 3]);
+    assert_eq!(Instant::now() - start, period * 3);
+}
+
+#[tokio::test]
+async fn recv_skip_lagged_until_closed() {
+    let (tx, mut rx) = broadcast::channel(4);
+    for i in 0..10 {
+        tx.send(i).unwrap();
+    }
+    // overfilled, only the last 4 values remain.
+    assert_eq!(rx.recv_skip_lagged().await, Ok(6));
+
+    for i in 10..20 {
+        tx.send(i).unwrap();
+    }
+    let mut received = Vec::new();
+    let sender = tokio::spawn(async move {
+        task::yield_now().await;
+        tx.send(20).unwrap();
+    });
+    while let Ok(value) = rx.recv_skip_lagged().await {
+        received.push(value);
+    }
+    sender.await.unwrap();
+    assert_eq!(received, [16, 17, 18, 19, 20]);
+    assert_eq!(
+        rx.recv_skip_lagged().await,
+        Err(broadcast::error::RecvError::Closed)
+    );
+}
+
+#[tokio::test]
+async fn recv_latest_skips_buffered_and_lagged_values() {
+    let (tx, mut rx) = broadcast::channel(4);
+    tx.send(0).unwrap();
+    assert_eq!(rx.recv_latest().await, Ok(0));
+
+    // more than the channel holds, so the receiver has also lagged.
+    for i in 1..10 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(rx.recv_latest().await, Ok(9));
+
+    let waiting = tokio::spawn(async move { rx.recv_latest().await });
+    task::yield_now().await;
+    tx.send(10).unwrap();
+    assert_eq!(waiting.await.unwrap(), Ok(10));
+}
+
+#[tokio::test]
+async fn recv_latest_after_close() {
+    let (tx, mut rx) = broadcast::channel(4);
+    tx.send("a").unwrap();
+    tx.send("b").unwrap();
+    drop(tx);
+    // values sent before the close are still received.
+    assert_eq!(rx.recv_latest().await, Ok("b"));
+    assert_eq!(
+        rx.recv_latest().await,
+        Err(broadcast::error::RecvError::Closed)
+    );
+}
+
+#[test]
+fn block_on_future_through_handle() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .build()
+        .unwrap();
+    let handle = runtime.handle().clone();
+    // the spawned task runs on the runtime's worker while another
+    // thread blocks on the result through the handle.
+    let task = runtime.spawn(async { 5 });
+
+    let value = std::thread::spawn(move || handle.block_on_future(task))
+        .join()
+        .unwrap();
+    assert_eq!(value.unwrap(), 5);
+    assert_eq!(runtime.block_on_future(async { 1 }), 1);
+}
+
+#[tokio::test]
+async fn recv_many_in_batches() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    for i in 0..10 {
+        tx.send(i).unwrap();
+    }
+    let mut buf = Vec::new();
+    assert_eq!(rx.recv_many_or_closed(&mut buf, 4).await, 4);
+    assert_eq!(rx.recv_many_or_closed(&mut buf, 0).await, 0);
+    assert_eq!(rx.recv_many_or_closed(&mut buf, 100).await, 6);
+    assert_eq!(buf, (0..10).collect::<Vec<_>>());
+
+    // values sent before closing are still received.
+    tx.send(10).unwrap();
+    drop(tx);
+    buf.clear();
+    assert_eq!(rx.recv_many_or_closed(&mut buf, 4).await, 1);
+    assert_eq!(buf, [10]);
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+#[should_panic(expected = "receiving with no senders")]
+async fn recv_many_after_sender_drop() {
+    let (tx, mut rx) = mpsc::channel::<i32>(4);
+    tx.send(1).await.unwrap();
+    drop(tx);
+    let mut buf = Vec::new();
+    rx.recv_many_or_closed(&mut buf, 4).await;
+    rx.recv_many_or_closed(&mut buf, 4).await;
+}
+
+#[tokio::test]
+async fn split_loopback_stream() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let client = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (server, _) = listener.accept().await.unwrap();
+
+    // echo everything back through the other half.
+    let (mut server_read, mut server_write) = server.split_owned();
+    let echo = tokio::spawn(async move {
+        let mut buf = Vec::new();
+        server_read.read_to_end(&mut buf).await.unwrap();
+        server_write.write_all(&buf).await.unwrap();
+    });
+
+    let (mut client_read, mut client_write) = client.split_owned();
+    client_write.write_all(b"ping").await.unwrap();
+    client_write.shutdown().await.unwrap();
+    let mut buf = Vec::new();
+    client_read.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, b"ping");
+    echo.await.unwrap();
+
+    // the halves still belong to the same socket.
+    assert!(client_read.reunite(client_write).is_ok());
+}
+
+#[tokio::test(start_paused = true)]
+async fn await_closed_after_receivers_drop() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    // the receiver is dropped after the sender starts waiting.
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        drop(rx);
+    });
+    tx.share().await_closed().await;
+    assert!(tx.is_closed());
+}
+
+#[tokio::test]
+async fn await_closed_when_already_closed() {
+    let (tx, rx) = mpsc::unbounded_channel::<i32>();
+    drop(rx);
+    tx.await_closed().await;
+}
+
+#[tokio::test]
+async fn watch_await_closed_after_all_receivers_drop() {
+    let (tx, rx) = watch::channel(0);
+    let rx1 = rx.share();
+    let rx2 = tx.subscribe();
+    drop(rx);
+
+    let producer = tx.share();
+    let closed = tokio::spawn(async move { producer.await_closed().await });
+    task::yield_now().await;
+    assert!(!closed.is_finished());
+
+    drop(rx1);
+    task::yield_now().await;
+    assert!(!closed.is_finished());
+
+    drop(rx2);
+    closed.await.unwrap();
+    assert_eq!(tx.receiver_count(), 0);
+}
+
+#[tokio::test]
+async fn set_once_cell_twice() {
+    let cell = std::sync::Arc::new(tokio::sync::OnceCell::new());
+    cell.set(String::from("a")).or_already_set();
+
+    let twice = cell.share();
+    let err = task::spawn(async move { twice.set(String::from("b")).or_already_set() })
+        .await
+        .unwrap_err();
+    assert_eq!(panic_message(err), "cell already initialized");
+    assert_eq!(cell.get().map(String::as_str), Some("a"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "cell is being initialized")]
+async fn set_once_cell_while_initializing() {
+    let cell = std::sync::Arc::new(tokio::sync::OnceCell::<i32>::new());
+    let initializing = cell.share();
+    // this initializer never finishes, so the cell stays in-flight.
+    tokio::spawn(async move {
+        initializing.get_or_init(std::future::pending::<i32>).await;
+    });
+    task::yield_now().await;
+    cell.set(1).or_already_set();
+}
+
+#[tokio::test(start_paused = true)]
+async fn owned_guards_move_into_tasks() {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let lock = Arc::new(RwLock::new(Vec::new()));
+    let mut writer = ReadWriteOwned::write_owned(lock.share()).await;
+    let write_task = tokio::spawn(async move {
+        // held across an await point.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        writer.push(1);
+    });
+
+    let reader = lock.share();
+    let read_task = tokio::spawn(async move { ReadWriteOwned::read_owned(reader).await.clone() });
+    write_task.await.unwrap();
+    // the reader had to wait for the writer to release the lock.
+    assert_eq!(read_task.await.unwrap(), [1]);
+    assert_eq!(Arc::strong_count(&lock), 1);
+}
+
+#[tokio::test]
+async fn drain_after_senders_drop() {
+    let (tx, mut rx) = mpsc::channel(4);
+    let producer = tokio::spawn({
+        let tx = tx.share();
+        async move {
+            // more values than the channel can buffer.
+            for i in 0..10 {
+                tx.send(i).await.unwrap();
+            }
+        }
+    });
+    tx.send(-1).await.unwrap();
+    drop(tx);
+
+    let values = rx.drain().await;
+    producer.await.unwrap();
+    assert_eq!(values[0], -1);
+    assert_eq!(values[1..], (0..10).collect::<Vec<_>>());
+    // nothing left once drained.
+    assert_eq!(rx.drain().await, []);
+}
+
+#[tokio::test]
+async fn drain_unbounded_buffered_values() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    for i in 0..3 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+    assert_eq!(rx.drain().await, [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn reserve_on_open_channel() {
+    let (tx, mut rx) = mpsc::channel(2);
+    let first = tx.reserve_or_closed().await;
+    let second = tx.try_reserve_or_full();
+    // permits send in the order they are used, not reserved.
+    second.send("second");
+    first.send("first");
+    assert_eq!(rx.recv().await, Some("second"));
+    assert_eq!(rx.recv().await, Some("first"));
+}
+
+#[tokio::test]
+async fn try_reserve_on_full_channel() {
+    let (tx, _rx) = mpsc::channel(1);
+    tx.try_reserve_or_full().send(1);
+    let err = task::spawn(async move {
+        tx.try_reserve_or_full();
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(panic_message(err), "channel full");
+}
+
+#[cfg(not(feature = "abort-on-closed"))]
+#[tokio::test]
+async fn reserve_on_closed_channel() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    drop(rx);
+    let waiting = tx.share();
+    let err = task::spawn(async move {
+        waiting.reserve_or_closed().await;
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(panic_message(err), "sending with disconnected receiver");
+
+    let err = task::spawn(async move {
+        tx.try_reserve_or_full();
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(panic_message(err), "sending with disconnected receiver");
+}
+
+fn metrics(end: &impl ChannelMetrics) -> (usize, usize, bool) {
+    (end.queued(), end.free_slots(), end.is_drained())
+}
+
+#[tokio::test]
+async fn channel_metrics_track_sends_and_receives() {
+    let (tx, mut rx) = mpsc::channel(3);
+    assert_eq!(metrics(&tx), (0, 3, true));
+    assert_eq!(metrics(&rx), (0, 3, true));
+
+    tx.send(1).await.unwrap();
+    tx.send(2).await.unwrap();
+    assert_eq!(metrics(&tx), (2, 1, false));
+    assert_eq!(metrics(&rx), (2, 1, false));
+
+    rx.recv().await.unwrap();
+    assert_eq!(metrics(&tx), (1, 2, false));
+    rx.recv().await.unwrap();
+    assert_eq!(metrics(&rx), (0, 3, true));
+}
+
+#[tokio::test]
+async fn channel_metrics_count_reserved_slots_on_both_ends() {
+    let (tx, rx) = mpsc::channel::<i32>(2);
+    let permit = tx.reserve().await.unwrap();
+    assert_eq!(metrics(&tx), (1, 1, false));
+    assert_eq!(metrics(&rx), (1, 1, false));
+
+    drop(permit);
+    assert_eq!(metrics(&tx), (0, 2, true));
+    assert_eq!(metrics(&rx), (0, 2, true));
+}
+
+#[tokio::test]
+async fn owned_mutex_guard_moves_into_a_task() {
+    use std::sync::Arc;
+
+    let state = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    let mut guard = LockOwned::lock_owned(state.share()).await.or_poisoned();
+    let task = tokio::spawn(async move {
+        task::yield_now().await;
+        guard.push("from task");
+    });
+    // the guard keeps the mutex locked until the task drops it.
+    assert!(state.try_lock().is_err());
+
+    task.await.unwrap();
+    assert_eq!(*state.lock().await, ["from task"]);
+    assert_eq!(Arc::strong_count(&state), 1);
+}
+
+#[tokio::test]
+async fn lock_blocking_from_spawn_blocking() {
+    use std::sync::Arc;
+
+    let state = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    state.lock().await.push("async");
+
+    let shared = state.share();
+    task::spawn_blocking(move || {
+        shared.lock_blocking().or_poisoned().push("blocking");
+    })
+    .await
+    .unwrap();
+    assert_eq!(*state.lock().await, ["async", "blocking"]);
+}
+
+#[tokio::test]
+async fn tokio_mutex_is_not_poisoned_by_panics() {
+    use std::sync::Arc;
+
+    let state = Arc::new(tokio::sync::Mutex::new(0));
+    let shared = state.share();
+    let err = task::spawn_blocking(move || {
+        let mut guard = shared.lock_blocking();
+        *guard = 1;
+        panic!("while holding the lock");
+    })
+    .await
+    .unwrap_err();
+    assert!(err.is_panic());
+    assert_eq!(*state.lock().await.or_poisoned(), 1);
+}
+
+#[tokio::test]
+async fn try_send_or_drop_when_full_or_closed() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Metric(Arc<AtomicUsize>);
+
+    impl Drop for Metric {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let metric = || Metric(dropped.share());
+    let (tx, mut rx) = mpsc::channel(2);
+    assert!(tx.try_send_or_drop(metric()));
+    assert!(tx.share().try_send_or_drop(metric()));
+    assert_eq!(dropped.load(Ordering::SeqCst), 0);
+
+    for _ in 0..3 {
+        assert!(!tx.try_send_or_drop(metric()));
+    }
+    assert_eq!(dropped.load(Ordering::SeqCst), 3);
+
+    // making space lets sends through again.
+    drop(rx.recv().await);
+    assert!(tx.try_send_or_drop(metric()));
+
+    drop(rx);
+    assert_eq!(dropped.load(Ordering::SeqCst), 6);
+    assert!(!tx.try_send_or_drop(metric()));
+    assert_eq!(dropped.load(Ordering::SeqCst), 7);
+}
+
+#[tokio::test]
+async fn send_to_any_counts_subscribers() {
+    let (tx, rx) = broadcast::channel(4);
+    assert_eq!(tx.send_to_any(1), Some(1));
+    let mut rx1 = tx.subscribe();
+    assert_eq!(tx.share().send_to_any(2), Some(2));
+    drop(rx);
+    drop(rx1);
+
+    // nobody is listening, but the channel can still be subscribed to.
+    assert_eq!(tx.send_to_any(3), None);
+    rx1 = tx.subscribe();
+    assert_eq!(tx.send_to_any(4), Some(1));
+    assert_eq!(rx1.recv().await, Ok(4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn send_if_changed_only_notifies_on_change() {
+    use std::time::Duration;
+
+    let (tx, mut rx) = watch::channel(String::from("a"));
+    rx.mark_unchanged();
+    assert!(!tx.send_if_changed(String::from("a")));
+    // nothing to wake up for.
+    let waited = tokio::time::timeout(Duration::from_secs(1), rx.changed()).await;
+    assert!(waited.is_err());
+
+    let producer = tx.share();
+    tokio::spawn(async move { assert!(producer.send_if_changed(String::from("b"))) });
+    rx.changed().await.unwrap();
+    assert_eq!(*rx.borrow_and_update(), "b");
+
+    // updates are kept with no one watching.
+    drop(rx);
+    assert!(tx.send_if_changed(String::from("c")));
+    assert_eq!(*tx.borrow(), "c");
+}
+
+#[tokio::test]
+async fn await_all_when_every_task_succeeds() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut set = task::JoinSet::new();
+    for i in 0..4 {
+        let tx = tx.share();
+        set.spawn(async move { tx.send(i).unwrap() });
+    }
+    // cancelled tasks don't count as failures.
+    set.spawn(std::future::pending::<()>()).abort();
+    drop(tx);
+
+    set.await_all_or_panic().await;
+    assert!(set.is_empty());
+    let mut values = rx.drain().await;
+    values.sort();
+    assert_eq!(values, [0, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn await_all_panics_if_a_task_panicked() {
+    let mut set = task::JoinSet::new();
+    set.spawn(async {});
+    set.spawn(async { panic!("worker failed") });
+    let err = tokio::spawn(async move { set.await_all_or_panic().await })
+        .await
+        .unwrap_err();
+    assert!(panic_message(err).starts_with("task panicked"));
+}
+
+#[tokio::test]
+async fn or_try_init_failure_is_retryable() {
+    let cell = tokio::sync::OnceCell::<u32>::new();
+    let failed = cell.or_try_init(|| async { Err("not ready") }).await;
+    assert_eq!(failed, Err("not ready"));
+    assert!(!cell.initialized());
+
+    assert_eq!(cell.or_try_init(|| async { Ok::<_, ()>(1) }).await, Ok(&1));
+    // cached, the initializer isn't run again.
+    let cached = cell.or_try_init(|| async { unreachable!() }).await;
+    assert_eq!(cached, Ok::<_, ()>(&1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn or_try_init_runs_one_initializer_at_a_time() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    let cell = Arc::new(tokio::sync::OnceCell::new());
+    let runs = Arc::new(AtomicUsize::new(0));
+    let tasks: Vec<_> = (0..4)
+        .map(|i| {
+            let cell = cell.share();
+            let runs = runs.share();
+            tokio::spawn(async move {
+                let value = cell
+                    .or_try_init(|| async move {
+                        runs.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        // the first initializer fails, so the next one
+                        // gets a turn.
+                        if i == 0 { Err(()) } else { Ok(i) }
+                    })
+                    .await;
+                value.copied()
+            })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for task in tasks {
+        results.push(task.await.unwrap());
+    }
+    assert_eq!(results, [Err(()), Ok(1), Ok(1), Ok(1)]);
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+}
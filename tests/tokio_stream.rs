@@ -0,0 +1,109 @@
+#![cfg(feature = "tokio-stream")]
+
+use semtraits::{IntoStream, Share};
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn stream_ends_after_all_senders_drop() {
+    let (tx, rx) = mpsc::channel(2);
+    for id in 0..3 {
+        let tx = tx.share();
+        tokio::spawn(async move { tx.send(id).await.unwrap() });
+    }
+    drop(tx);
+
+    let mut values: Vec<i32> = rx.into_stream().collect().await;
+    values.sort();
+    assert_eq!(values, [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn unbounded_stream_yields_buffered_values_then_ends() {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tx.send("a").unwrap();
+    tx.send("b").unwrap();
+    drop(tx);
+
+    let mut stream = rx.into_stream();
+    assert_eq!(stream.next().await, Some("a"));
+    assert_eq!(stream.next().await, Some("b"));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn stream_can_recover_the_receiver() {
+    let (tx, rx) = mpsc::channel(1);
+    let stream = rx.into_stream();
+    let mut rx = stream.into_inner();
+    tx.send(1).await.unwrap();
+    assert_eq!(rx.recv().await, Some(1));
+}
+
+#[tokio::test]
+async fn broadcast_stream_reports_lag() {
+    use tokio::sync::broadcast;
+    use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+    let (tx, rx) = broadcast::channel(2);
+    let other = tx.subscribe().into_stream();
+    let mut stream = rx.into_stream();
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(
+        stream.next().await,
+        Some(Err(BroadcastStreamRecvError::Lagged(3)))
+    );
+    assert_eq!(stream.next().await, Some(Ok(3)));
+
+    // every subscriber gets its own copy.
+    drop(tx);
+    let rest: Vec<_> = other.filter_map(Result::ok).collect().await;
+    assert_eq!(rest, [3, 4]);
+    assert_eq!(stream.next().await, Some(Ok(4)));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn broadcast_stream_skips_lagged_values() {
+    use semtraits::SkipLagged;
+    use tokio::sync::broadcast;
+
+    let (tx, rx) = broadcast::channel(4);
+    let stream = rx.into_stream().skip_lagged();
+    let producer = tokio::spawn(async move {
+        for i in 0..10 {
+            tx.send(i).unwrap();
+        }
+        tokio::task::yield_now().await;
+        tx.send(10).unwrap();
+    });
+    tokio::pin!(stream);
+    // wait for the first burst, which overfills the channel.
+    let first = stream.next().await;
+    assert_eq!(first, Some(6));
+    let values: Vec<_> = stream.collect().await;
+    producer.await.unwrap();
+    assert_eq!(values, [7, 8, 9, 10]);
+}
+
+#[tokio::test]
+async fn watch_stream_yields_current_then_changes() {
+    use tokio::sync::watch;
+
+    let (tx, rx) = watch::channel("initial");
+    let mut stream = rx.into_stream();
+    assert_eq!(stream.next().await, Some("initial"));
+
+    tx.send("first").unwrap();
+    assert_eq!(stream.next().await, Some("first"));
+
+    // only the latest value is kept between polls.
+    tx.send("second").unwrap();
+    tx.send("third").unwrap();
+    assert_eq!(stream.next().await, Some("third"));
+
+    drop(tx);
+    assert_eq!(stream.next().await, None);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "tokio-util")]
+
+use futures::{SinkExt, StreamExt, stream};
+use semtraits::{IntoSink, Share};
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn feed_a_stream_through_the_sink() {
+    let (tx, mut rx) = mpsc::channel(2);
+    let mut sink = tx.share().into_sink();
+    let producer = tokio::spawn(async move {
+        // more items than the channel can hold at once.
+        let mut items = stream::iter(0..5).map(Ok);
+        sink.send_all(&mut items).await.unwrap();
+    });
+
+    let mut received = Vec::new();
+    for _ in 0..5 {
+        received.push(rx.recv().await.unwrap());
+    }
+    producer.await.unwrap();
+    assert_eq!(received, [0, 1, 2, 3, 4]);
+
+    // the original sender is still open.
+    tx.send(5).await.unwrap();
+    assert_eq!(rx.recv().await, Some(5));
+}
+
+#[tokio::test]
+async fn send_into_closed_channel() {
+    let (tx, rx) = mpsc::channel(1);
+    drop(rx);
+    let mut sink = tx.into_sink();
+    let err = sink.send(1).await.unwrap_err();
+    assert_eq!(err.into_inner(), None);
+    assert!(sink.is_closed());
+}
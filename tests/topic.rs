@@ -0,0 +1,71 @@
+#![cfg(feature = "tokio")]
+
+use semtraits::{RecvSkipLagged, Share, shared::Topic};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+#[tokio::test]
+async fn every_subscriber_receives_published_values() {
+    let topic = Topic::new(8);
+    let mut subscriptions: Vec<_> = (0..3).map(|_| topic.subscribe()).collect();
+    assert_eq!(topic.subscriber_count(), 3);
+
+    let publisher = topic.share();
+    tokio::spawn(async move {
+        publisher.publish(1);
+        publisher.publish(2);
+    })
+    .await
+    .unwrap();
+
+    for subscription in &mut subscriptions {
+        assert_eq!(subscription.recv_skip_lagged().await, Ok(1));
+        assert_eq!(subscription.recv_skip_lagged().await, Ok(2));
+    }
+}
+
+#[tokio::test]
+async fn publish_without_subscribers_is_dropped() {
+    let topic = Topic::new(1);
+    topic.publish("unseen");
+    let mut late = topic.subscribe();
+    topic.publish("seen");
+    assert_eq!(late.recv_skip_lagged().await, Ok("seen"));
+}
+
+#[tokio::test]
+async fn lagging_subscriber_skips_ahead() {
+    let topic = Topic::new(2);
+    let mut slow = topic.subscribe();
+    for i in 0..5 {
+        topic.publish(i);
+    }
+    assert_eq!(slow.recv_skip_lagged().await, Ok(3));
+    assert_eq!(slow.recv_skip_lagged().await, Ok(4));
+
+    drop(topic);
+    assert_eq!(slow.recv_skip_lagged().await, Err(RecvError::Closed));
+}
+
+/// Collects values until the channel closes, from a subscription or a
+/// plain broadcast receiver.
+async fn collect<R: RecvSkipLagged>(mut rx: R) -> Vec<R::Item> {
+    let mut values = Vec::new();
+    while let Ok(value) = rx.recv_skip_lagged().await {
+        values.push(value);
+    }
+    values
+}
+
+#[tokio::test]
+async fn subscriptions_work_with_generic_receivers() {
+    let topic = Topic::new(4);
+    let subscription = topic.subscribe();
+    topic.publish(1);
+    drop(topic);
+    assert_eq!(collect(subscription).await, [1]);
+
+    let (tx, rx) = broadcast::channel(4);
+    tx.send(2).unwrap();
+    drop(tx);
+    assert_eq!(collect(rx).await, [2]);
+}
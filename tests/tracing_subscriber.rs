@@ -0,0 +1,47 @@
+#![cfg(feature = "tracing-subscriber")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use semtraits::{Reload, Share};
+use tracing::subscriber::Subscriber;
+use tracing_subscriber::{Layer, filter::LevelFilter, layer::Context, prelude::*, reload};
+
+/// Counts the events that make it past the filter.
+struct Count(Arc<AtomicUsize>);
+
+impl<S: Subscriber> Layer<S> for Count {
+    fn on_event(&self, _: &tracing::Event<'_>, _: Context<'_, S>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn reloaded_filter_takes_effect() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let (filter, handle) = reload::Layer::new(LevelFilter::WARN);
+    let subscriber = tracing_subscriber::registry().with(Count(count.share()).with_filter(filter));
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!("filtered out");
+        tracing::warn!("kept");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        handle.share().reload_layer(LevelFilter::INFO);
+        tracing::info!("now kept");
+        tracing::debug!("still filtered out");
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        handle.reload_layer(LevelFilter::OFF);
+        tracing::error!("everything is filtered");
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "reloading a dropped subscriber")]
+fn reload_after_subscriber_dropped() {
+    let (filter, handle) = reload::Layer::<_, tracing_subscriber::Registry>::new(LevelFilter::WARN);
+    drop(tracing_subscriber::registry().with(filter));
+    handle.reload_layer(LevelFilter::INFO);
+}
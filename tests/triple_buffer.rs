@@ -0,0 +1,31 @@
+#![cfg(feature = "triple-buffer")]
+
+use std::thread;
+
+use semtraits::{Peek, Publish};
+use triple_buffer::triple_buffer;
+
+#[test]
+fn reader_sees_only_the_latest_value() {
+    let (mut input, mut output) = triple_buffer(&String::new());
+    for value in ["a", "b", "c"] {
+        input.publish_value(value.to_string());
+    }
+    assert_eq!(output.latest(), "c");
+    // reading again without a new write gives the same value.
+    assert_eq!(output.latest(), "c");
+}
+
+#[test]
+fn publish_from_another_thread() {
+    let (mut input, mut output) = triple_buffer(&0);
+    assert_eq!(*output.latest(), 0);
+    thread::spawn(move || {
+        for value in 1..=10 {
+            input.publish_value(value);
+        }
+    })
+    .join()
+    .unwrap();
+    assert_eq!(*output.latest(), 10);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "weak-table")]
+
+use std::sync::Arc;
+use std::thread;
+
+use semtraits::{Share, shared::SharedWeakCache};
+
+#[test]
+fn dropped_values_are_evicted() {
+    let cache = SharedWeakCache::new();
+    let one = cache.get_or_insert_with(1, || "one");
+    let two = cache.get_or_insert_with(2, || "two");
+    assert_eq!(cache.len(), 2);
+
+    drop(one);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.get(&2).as_deref(), Some(&"two"));
+
+    drop(two);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn evicted_values_are_reinserted() {
+    let cache = SharedWeakCache::new();
+    let first = cache.get_or_insert_with("key", || vec![1]);
+    let weak = Arc::downgrade(&first);
+    drop(first);
+    assert!(weak.upgrade().is_none());
+
+    let second = cache.get_or_insert_with("key", || vec![2]);
+    assert_eq!(*second, [2]);
+    assert!(Arc::ptr_eq(&second, &cache.get(&"key").unwrap()));
+}
+
+#[test]
+fn shared_between_threads() {
+    let cache = SharedWeakCache::new();
+    let value = cache.get_or_insert_with(0, || String::from("shared"));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let cache = cache.share();
+            thread::spawn(move || cache.get_or_insert_with(0, || unreachable!()))
+        })
+        .collect();
+    for handle in handles {
+        assert!(Arc::ptr_eq(&value, &handle.join().unwrap()));
+    }
+}